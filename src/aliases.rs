@@ -0,0 +1,66 @@
+use crate::{Resume, slugify};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AliasMap {
+    pub skills: BTreeMap<String, String>,
+    pub projects: BTreeMap<String, String>,
+}
+
+impl AliasMap {
+    pub fn resolve_skill<'a>(&'a self, name: &'a str) -> &'a str {
+        self.skills.get(name).map(String::as_str).unwrap_or(name)
+    }
+
+    pub fn resolve_project<'a>(&'a self, name: &'a str) -> &'a str {
+        self.projects.get(name).map(String::as_str).unwrap_or(name)
+    }
+}
+
+pub fn alias_variants(name: &str) -> Vec<String> {
+    let name = name.trim();
+    let mut variants = vec![name.to_string(), name.to_lowercase(), slugify(name)];
+    variants.retain(|variant| is_file_safe(variant));
+    variants.dedup();
+    variants
+}
+
+// Aliases, ids and tool arguments become file names, so none of them may escape the tool directory.
+pub(crate) fn is_file_safe(alias: &str) -> bool {
+    !alias.is_empty() && alias != "." && alias != ".." && !alias.contains(['/', '\\'])
+}
+
+fn resolve<'a>(entries: impl Iterator<Item = (&'a str, Vec<String>)>) -> BTreeMap<String, String> {
+    let entries: Vec<(&str, Vec<String>)> = entries.collect();
+    let ids: HashSet<&str> = entries.iter().map(|(id, _)| *id).collect();
+    let mut candidates: BTreeMap<String, BTreeSet<&str>> = BTreeMap::new();
+    for (id, names) in &entries {
+        for variant in names.iter().flat_map(|name| alias_variants(name)) {
+            candidates.entry(variant).or_default().insert(id);
+        }
+    }
+    // An id always wins over an alias, and an alias shared by several entries resolves to none.
+    candidates
+        .into_iter()
+        .filter(|(alias, targets)| targets.len() == 1 && !ids.contains(alias.as_str()))
+        .map(|(alias, targets)| (alias, targets.into_iter().next().unwrap().to_string()))
+        .collect()
+}
+
+pub fn build_alias_map(resume: &Resume) -> AliasMap {
+    let skills = resume.skills.iter().map(|skill| {
+        let mut names = vec![skill.id.clone(), skill.name.clone()];
+        names.extend(skill.aliases.iter().cloned());
+        (skill.id.as_str(), names)
+    });
+    let projects = resume.projects.iter().map(|project| {
+        let mut names = vec![project.id.clone(), project.title.clone()];
+        names.extend(project.aliases.iter().cloned());
+        (project.id.as_str(), names)
+    });
+    AliasMap {
+        skills: resolve(skills),
+        projects: resolve(projects),
+    }
+}
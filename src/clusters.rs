@@ -0,0 +1,100 @@
+use crate::Project;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SkillCluster {
+    pub skills: Vec<String>,
+    pub support: usize,
+    pub projects: Vec<String>,
+}
+
+type Itemset = Vec<String>;
+
+fn supporting<'a>(transactions: &[(&'a str, BTreeSet<&str>)], itemset: &[String]) -> Vec<&'a str> {
+    transactions
+        .iter()
+        .filter(|(_, skills)| itemset.iter().all(|skill| skills.contains(skill.as_str())))
+        .map(|(id, _)| *id)
+        .collect()
+}
+
+// Joins itemsets sharing all but their last skill, then drops any candidate with an
+// infrequent subset.
+fn candidates(frequent: &BTreeSet<Itemset>) -> Vec<Itemset> {
+    let itemsets: Vec<&Itemset> = frequent.iter().collect();
+    let mut candidates = Vec::new();
+    for (i, a) in itemsets.iter().enumerate() {
+        for b in &itemsets[i + 1..] {
+            let prefix = a.len() - 1;
+            if a[..prefix] != b[..prefix] {
+                break;
+            }
+            let mut candidate = (*a).clone();
+            candidate.push(b[prefix].clone());
+            let all_subsets_frequent = (0..candidate.len()).all(|skip| {
+                let subset: Itemset = candidate
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| *j != skip)
+                    .map(|(_, skill)| skill.clone())
+                    .collect();
+                frequent.contains(&subset)
+            });
+            if all_subsets_frequent {
+                candidates.push(candidate);
+            }
+        }
+    }
+    candidates
+}
+
+pub fn find_skill_clusters(projects: &[Project], min_support: usize, max_size: usize) -> Vec<SkillCluster> {
+    let min_support = min_support.max(1);
+    let transactions: Vec<(&str, BTreeSet<&str>)> = projects
+        .iter()
+        .map(|p| (p.id.as_str(), p.skills.iter().map(String::as_str).collect()))
+        .collect();
+
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for (_, skills) in &transactions {
+        for skill in skills {
+            *counts.entry(skill).or_default() += 1;
+        }
+    }
+    let mut frequent: BTreeSet<Itemset> = counts
+        .into_iter()
+        .filter(|(_, count)| *count >= min_support)
+        .map(|(skill, _)| vec![skill.to_string()])
+        .collect();
+
+    let mut clusters = Vec::new();
+    for _ in 2..=max_size {
+        let mut next = BTreeSet::new();
+        for candidate in candidates(&frequent) {
+            let supported_by = supporting(&transactions, &candidate);
+            if supported_by.len() >= min_support {
+                let mut project_ids: Vec<String> = supported_by.iter().map(|id| id.to_string()).collect();
+                project_ids.sort();
+                clusters.push(SkillCluster {
+                    skills: candidate.clone(),
+                    support: supported_by.len(),
+                    projects: project_ids,
+                });
+                next.insert(candidate);
+            }
+        }
+        if next.is_empty() {
+            break;
+        }
+        frequent = next;
+    }
+
+    clusters.sort_by(|a, b| {
+        b.support
+            .cmp(&a.support)
+            .then(b.skills.len().cmp(&a.skills.len()))
+            .then_with(|| a.skills.cmp(&b.skills))
+    });
+    clusters
+}
@@ -0,0 +1,383 @@
+use crate::{
+    Error, Experience, JsonSchema, MCPToolResult, Project, Result, Skill, StaticTool, ToolContext,
+    aliases::is_file_safe, builtin_tools,
+    prompts::{is_valid_name, placeholders},
+    render_prompt,
+    schema::wrap_output_schema,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArgumentDomain {
+    Skill,
+    Project,
+    Experience,
+    SkillCategory,
+    SkillType,
+}
+
+impl ArgumentDomain {
+    pub fn values(&self, context: &ToolContext) -> Vec<String> {
+        let resume = context.resume;
+        let values: BTreeSet<&String> = match self {
+            ArgumentDomain::Skill => return resume.skills.iter().map(|s| s.id.clone()).collect(),
+            ArgumentDomain::Project => return resume.projects.iter().map(|p| p.id.clone()).collect(),
+            ArgumentDomain::Experience => {
+                return resume.experiences.iter().map(|e| e.id.clone()).collect();
+            }
+            ArgumentDomain::SkillCategory => resume.skills.iter().map(|s| &s.category).collect(),
+            ArgumentDomain::SkillType => resume.skills.iter().map(|s| &s.skill_type).collect(),
+        };
+        values.into_iter().cloned().collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomToolTarget {
+    Projects,
+    Experiences,
+    Skills,
+}
+
+impl CustomToolTarget {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CustomToolTarget::Projects => "projects",
+            CustomToolTarget::Experiences => "experiences",
+            CustomToolTarget::Skills => "skills",
+        }
+    }
+
+    pub fn filter_fields(&self) -> &'static [&'static str] {
+        match self {
+            CustomToolTarget::Projects => &[
+                "id",
+                "title",
+                "skill",
+                "skill.category",
+                "skill.type",
+                "experience",
+                "experience.employer",
+            ],
+            CustomToolTarget::Experiences => &[
+                "id",
+                "title",
+                "employer",
+                "project",
+                "skill",
+                "skill.category",
+                "skill.type",
+            ],
+            CustomToolTarget::Skills => &[
+                "id",
+                "name",
+                "category",
+                "type",
+                "proficiency",
+                "project",
+                "experience",
+            ],
+        }
+    }
+
+    fn schema(&self) -> Value {
+        match self {
+            CustomToolTarget::Projects => Project::schema(),
+            CustomToolTarget::Experiences => Experience::schema(),
+            CustomToolTarget::Skills => Skill::schema(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomToolArgument {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub domain: ArgumentDomain,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomToolFilter {
+    pub field: String,
+    pub equals: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomTool {
+    pub name: String,
+    pub description: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub arguments: Vec<CustomToolArgument>,
+    pub select: CustomToolTarget,
+    #[serde(rename = "where", default, skip_serializing_if = "Vec::is_empty")]
+    pub filters: Vec<CustomToolFilter>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<String>,
+}
+
+fn skills_of<'a>(context: &'a ToolContext, ids: &'a [String]) -> impl Iterator<Item = &'a Skill> {
+    ids.iter().filter_map(|id| context.index.skill_lookup.get(id))
+}
+
+fn related<'a>(ids: Option<&'a Vec<String>>) -> impl Iterator<Item = String> + 'a {
+    ids.into_iter().flatten().cloned()
+}
+
+fn skill_values<'a>(skills: impl Iterator<Item = &'a Skill>, field: &str) -> Vec<String> {
+    skills
+        .map(|skill| match field {
+            "skill.category" => skill.category.clone(),
+            "skill.type" => skill.skill_type.clone(),
+            _ => skill.id.clone(),
+        })
+        .collect()
+}
+
+fn project_values(context: &ToolContext, project: &Project, field: &str) -> Vec<String> {
+    let experiences = context.index.project_to_experiences.get(&project.id);
+    match field {
+        "id" => vec![project.id.clone()],
+        "title" => vec![project.title.clone()],
+        "experience" => related(experiences).collect(),
+        "experience.employer" => related(experiences)
+            .filter_map(|id| context.index.experience_lookup.get(&id))
+            .map(|experience| experience.employer.clone())
+            .collect(),
+        _ => skill_values(skills_of(context, &project.skills), field),
+    }
+}
+
+fn experience_values(context: &ToolContext, experience: &Experience, field: &str) -> Vec<String> {
+    match field {
+        "id" => vec![experience.id.clone()],
+        "title" => vec![experience.title.clone()],
+        "employer" => vec![experience.employer.clone()],
+        "project" => experience.projects.clone(),
+        _ => {
+            let skills = experience
+                .projects
+                .iter()
+                .filter_map(|id| context.index.project_lookup.get(id))
+                .flat_map(|project| skills_of(context, &project.skills));
+            skill_values(skills, field)
+        }
+    }
+}
+
+fn skill_own_values(context: &ToolContext, skill: &Skill, field: &str) -> Vec<String> {
+    match field {
+        "id" => vec![skill.id.clone()],
+        "name" => vec![skill.name.clone()],
+        "category" => vec![skill.category.clone()],
+        "type" => vec![skill.skill_type.clone()],
+        "proficiency" => skill.proficiency.iter().map(|p| p.as_str().to_string()).collect(),
+        "project" => related(context.index.skill_to_projects.get(&skill.id)).collect(),
+        _ => related(context.index.skill_to_experiences.get(&skill.id)).collect(),
+    }
+}
+
+impl CustomTool {
+    fn error(&self, message: String) -> Error {
+        Error::CustomTool {
+            name: self.name.clone(),
+            message,
+        }
+    }
+
+    fn check_definition(&self) -> Result<()> {
+        if !is_valid_name(&self.name) {
+            return Err(self.error("names may only contain letters, digits, '_' and '-'".to_string()));
+        }
+        let mut names = BTreeSet::new();
+        for argument in &self.arguments {
+            if !is_valid_name(&argument.name) {
+                return Err(self.error(format!("invalid argument name '{}'", argument.name)));
+            }
+            if !names.insert(argument.name.as_str()) {
+                return Err(self.error(format!("argument '{}' is declared more than once", argument.name)));
+            }
+        }
+        for filter in &self.filters {
+            if !self.select.filter_fields().contains(&filter.field.as_str()) {
+                return Err(self.error(format!(
+                    "cannot filter {} on '{}', expected one of: {}",
+                    self.select.as_str(),
+                    filter.field,
+                    self.select.filter_fields().join(", ")
+                )));
+            }
+            for placeholder in placeholders(&filter.equals) {
+                if !names.contains(placeholder) {
+                    return Err(self.error(format!("unknown argument '{{{{{}}}}}'", placeholder)));
+                }
+            }
+        }
+        let schema = self.select.schema();
+        for field in &self.fields {
+            if schema["properties"].get(field).is_none() {
+                return Err(self.error(format!("unknown field '{}'", field)));
+            }
+        }
+        Ok(())
+    }
+
+    fn matches(&self, values: impl Fn(&str) -> Vec<String>, arguments: &BTreeMap<&str, String>) -> bool {
+        self.filters.iter().all(|filter| {
+            let expected = render_prompt(&filter.equals, arguments);
+            values(&filter.field).contains(&expected)
+        })
+    }
+
+    fn projection(&self, entity: Value) -> Value {
+        if self.fields.is_empty() {
+            return entity;
+        }
+        let Value::Object(mut entity) = entity else {
+            return entity;
+        };
+        let projected: Map<String, Value> = self
+            .fields
+            .iter()
+            .filter_map(|field| entity.remove(field).map(|value| (field.clone(), value)))
+            .collect();
+        Value::Object(projected)
+    }
+
+    fn select(&self, context: &ToolContext, arguments: &BTreeMap<&str, String>) -> Result<Vec<Value>> {
+        let mut selected = Vec::new();
+        match self.select {
+            CustomToolTarget::Projects => {
+                for project in &context.resume.projects {
+                    if self.matches(|field| project_values(context, project, field), arguments) {
+                        selected.push(serde_json::to_value(project)?);
+                    }
+                }
+            }
+            CustomToolTarget::Experiences => {
+                for experience in &context.resume.experiences {
+                    if self.matches(|field| experience_values(context, experience, field), arguments) {
+                        selected.push(serde_json::to_value(experience)?);
+                    }
+                }
+            }
+            CustomToolTarget::Skills => {
+                for skill in &context.resume.skills {
+                    if self.matches(|field| skill_own_values(context, skill, field), arguments) {
+                        selected.push(serde_json::to_value(skill)?);
+                    }
+                }
+            }
+        }
+        Ok(selected.into_iter().map(|entity| self.projection(entity)).collect())
+    }
+}
+
+impl StaticTool for CustomTool {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn input_schema(&self) -> Value {
+        let properties: Map<String, Value> = self
+            .arguments
+            .iter()
+            .map(|argument| {
+                let mut property = json!({"type": "string"});
+                if let Some(description) = &argument.description {
+                    property["description"] = json!(description);
+                }
+                (argument.name.clone(), property)
+            })
+            .collect();
+        let required: Vec<&str> = self.arguments.iter().map(|a| a.name.as_str()).collect();
+        json!({
+            "type": "object",
+            "properties": properties,
+            "required": required
+        })
+    }
+
+    fn output_schema(&self) -> Option<Value> {
+        let mut schema = self.select.schema();
+        if !self.fields.is_empty() {
+            let fields: BTreeSet<&str> = self.fields.iter().map(String::as_str).collect();
+            if let Some(properties) = schema["properties"].as_object_mut() {
+                properties.retain(|key, _| fields.contains(key.as_str()));
+            }
+            if let Some(required) = schema["required"].as_array_mut() {
+                required.retain(|key| key.as_str().is_some_and(|key| fields.contains(key)));
+            }
+        }
+        Some(wrap_output_schema(json!({"type": "array", "items": schema})))
+    }
+
+    fn check(&self, context: &ToolContext) -> Result<()> {
+        self.check_definition()?;
+        for argument in &self.arguments {
+            for value in argument.domain.values(context) {
+                if !is_file_safe(&value) {
+                    return Err(self.error(format!(
+                        "value '{}' of argument '{}' cannot be used as a file name",
+                        value, argument.name
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Every combination of values of the declared arguments.
+    fn arguments(&self, context: &ToolContext) -> Vec<Vec<String>> {
+        self.arguments
+            .iter()
+            .fold(vec![vec![]], |combinations, argument| {
+                let values = argument.domain.values(context);
+                combinations
+                    .iter()
+                    .flat_map(|combination| {
+                        values.iter().map(move |value| {
+                            let mut combination = combination.clone();
+                            combination.push(value.clone());
+                            combination
+                        })
+                    })
+                    .collect()
+            })
+    }
+
+    fn call(&self, context: &ToolContext, arguments: &[String]) -> Result<MCPToolResult> {
+        let arguments: BTreeMap<&str, String> = self
+            .arguments
+            .iter()
+            .map(|argument| argument.name.as_str())
+            .zip(arguments.iter().cloned())
+            .collect();
+        MCPToolResult::new(&self.select(context, &arguments)?)
+    }
+}
+
+// Replacing a built-in tool is left to `StaticGenerator::with_tool`, so that a config typo
+// cannot remove one.
+pub fn check_custom_tools(tools: &[CustomTool]) -> Result<()> {
+    let builtin: BTreeSet<String> = builtin_tools().iter().map(|tool| tool.name().to_string()).collect();
+    let mut names = BTreeSet::new();
+    for tool in tools {
+        if builtin.contains(&tool.name) {
+            return Err(tool.error("has the name of a built-in tool".to_string()));
+        }
+        if !names.insert(tool.name.as_str()) {
+            return Err(tool.error("defined more than once".to_string()));
+        }
+        tool.check_definition()?;
+    }
+    Ok(())
+}
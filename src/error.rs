@@ -0,0 +1,206 @@
+use crate::ValidationReport;
+use std::{fmt, io, path::PathBuf};
+
+#[derive(Debug)]
+pub enum Error {
+    ConfigRead {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    Convert {
+        field: String,
+        message: String,
+    },
+    Include {
+        path: PathBuf,
+        pattern: String,
+        message: String,
+    },
+    DuplicateId {
+        kind: &'static str,
+        id: String,
+        first: PathBuf,
+        second: PathBuf,
+    },
+    Validation(ValidationReport),
+    Prompt {
+        name: String,
+        message: String,
+    },
+    CustomTool {
+        name: String,
+        message: String,
+    },
+    NotPrecomputed {
+        tool: String,
+    },
+    UnknownArgument {
+        argument: String,
+        value: String,
+    },
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Serialize(serde_json::Error),
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Error {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::ConfigRead { .. } | Error::Include { .. } => 2,
+            Error::Parse { .. } | Error::Convert { .. } => 3,
+            Error::DuplicateId { .. } | Error::Validation(_) | Error::Prompt { .. } | Error::CustomTool { .. } => 4,
+            Error::Io { .. } => 5,
+            Error::Serialize(_) => 6,
+            Error::NotPrecomputed { .. } | Error::UnknownArgument { .. } => 7,
+        }
+    }
+
+    pub(crate) fn json_parse(path: impl Into<PathBuf>, err: serde_json::Error) -> Self {
+        let suffix = format!(" at line {} column {}", err.line(), err.column());
+        let message = err.to_string();
+        Error::Parse {
+            path: path.into(),
+            line: err.line(),
+            column: err.column(),
+            message: message.strip_suffix(&suffix).unwrap_or(&message).to_string(),
+        }
+    }
+
+    pub(crate) fn yaml_parse(path: impl Into<PathBuf>, err: serde_yaml::Error) -> Self {
+        let (line, column) = err
+            .location()
+            .map(|location| (location.line(), location.column()))
+            .unwrap_or((0, 0));
+        let suffix = format!(" at line {} column {}", line, column);
+        let message = err.to_string();
+        Error::Parse {
+            path: path.into(),
+            line,
+            column,
+            message: message.strip_suffix(&suffix).unwrap_or(&message).to_string(),
+        }
+    }
+
+    pub(crate) fn toml_parse(path: impl Into<PathBuf>, content: &str, err: toml::de::Error) -> Self {
+        let (line, column) = match err.span() {
+            Some(span) => {
+                let before = &content[..span.start.min(content.len())];
+                let line = before.matches('\n').count() + 1;
+                let column = before.chars().rev().take_while(|c| *c != '\n').count() + 1;
+                (line, column)
+            }
+            None => (0, 0),
+        };
+        Error::Parse {
+            path: path.into(),
+            line,
+            column,
+            message: err.message().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::ConfigRead { path, source } => {
+                write!(f, "failed to read config file {}: {}", path.display(), source)
+            }
+            Error::Parse {
+                path,
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "failed to parse {} at line {}, column {}: {}",
+                path.display(),
+                line,
+                column,
+                message
+            ),
+            Error::Convert { field, message } => {
+                write!(f, "failed to convert {}: {}", field, message)
+            }
+            Error::Include {
+                path,
+                pattern,
+                message,
+            } => write!(
+                f,
+                "invalid include '{}' in {}: {}",
+                pattern,
+                path.display(),
+                message
+            ),
+            Error::DuplicateId {
+                kind,
+                id,
+                first,
+                second,
+            } => write!(
+                f,
+                "duplicate {} id '{}' defined in {} and {}",
+                kind,
+                id,
+                first.display(),
+                second.display()
+            ),
+            Error::Validation(report) => write!(f, "{}", report),
+            Error::Prompt { name, message } => write!(f, "invalid prompt '{}': {}", name, message),
+            Error::CustomTool { name, message } => {
+                write!(f, "invalid custom tool '{}': {}", name, message)
+            }
+            Error::NotPrecomputed { tool } => {
+                write!(f, "tool '{}' is not pre-computed and must be answered by the bridge", tool)
+            }
+            Error::UnknownArgument { argument, value } => {
+                write!(f, "unknown {} '{}'", argument, value)
+            }
+            Error::Io { path, source } => {
+                write!(f, "failed to write {}: {}", path.display(), source)
+            }
+            Error::Serialize(source) => write!(f, "failed to serialize output: {}", source),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::ConfigRead { source, .. } | Error::Io { source, .. } => Some(source),
+            Error::Validation(report) => Some(report),
+            Error::Serialize(source) => Some(source),
+            Error::Parse { .. }
+            | Error::Convert { .. }
+            | Error::Include { .. }
+            | Error::DuplicateId { .. }
+            | Error::Prompt { .. }
+            | Error::CustomTool { .. }
+            | Error::NotPrecomputed { .. }
+            | Error::UnknownArgument { .. } => None,
+        }
+    }
+}
+
+impl From<ValidationReport> for Error {
+    fn from(report: ValidationReport) -> Self {
+        Error::Validation(report)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Serialize(err)
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{Resume, ResumeIndex};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SkillNode {
+    pub id: String,
+    pub name: String,
+    pub category: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SkillEdge {
+    pub source: String,
+    pub target: String,
+    pub weight: usize,
+    pub projects: usize,
+    pub experiences: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelatedSkill {
+    pub id: String,
+    pub name: String,
+    pub weight: usize,
+    pub projects: usize,
+    pub experiences: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SkillGraph {
+    pub nodes: Vec<SkillNode>,
+    pub edges: Vec<SkillEdge>,
+}
+
+fn dot_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+impl SkillGraph {
+    pub fn related(&self, skill_id: &str) -> Vec<RelatedSkill> {
+        let names: BTreeMap<&str, &str> = self
+            .nodes
+            .iter()
+            .map(|node| (node.id.as_str(), node.name.as_str()))
+            .collect();
+        let mut related: Vec<RelatedSkill> = self
+            .edges
+            .iter()
+            .filter_map(|edge| {
+                let other = if edge.source == skill_id {
+                    &edge.target
+                } else if edge.target == skill_id {
+                    &edge.source
+                } else {
+                    return None;
+                };
+                Some(RelatedSkill {
+                    id: other.clone(),
+                    name: names.get(other.as_str()).copied().unwrap_or_default().to_string(),
+                    weight: edge.weight,
+                    projects: edge.projects,
+                    experiences: edge.experiences,
+                })
+            })
+            .collect();
+        related.sort_by(|a, b| b.weight.cmp(&a.weight).then_with(|| a.id.cmp(&b.id)));
+        related
+    }
+
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("graph skills {\n");
+        for node in &self.nodes {
+            let _ = writeln!(
+                dot,
+                "  \"{}\" [label=\"{}\", category=\"{}\"];",
+                dot_escape(&node.id),
+                dot_escape(&node.name),
+                dot_escape(&node.category)
+            );
+        }
+        for edge in &self.edges {
+            let _ = writeln!(
+                dot,
+                "  \"{}\" -- \"{}\" [weight={}, label=\"{}\"];",
+                dot_escape(&edge.source),
+                dot_escape(&edge.target),
+                edge.weight,
+                edge.weight
+            );
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    pub fn to_graphml(&self) -> String {
+        let mut xml = String::from(concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n",
+            "  <key id=\"name\" for=\"node\" attr.name=\"name\" attr.type=\"string\"/>\n",
+            "  <key id=\"category\" for=\"node\" attr.name=\"category\" attr.type=\"string\"/>\n",
+            "  <key id=\"weight\" for=\"edge\" attr.name=\"weight\" attr.type=\"int\"/>\n",
+            "  <key id=\"projects\" for=\"edge\" attr.name=\"projects\" attr.type=\"int\"/>\n",
+            "  <key id=\"experiences\" for=\"edge\" attr.name=\"experiences\" attr.type=\"int\"/>\n",
+            "  <graph id=\"skills\" edgedefault=\"undirected\">\n",
+        ));
+        for node in &self.nodes {
+            let _ = writeln!(xml, "    <node id=\"{}\">", xml_escape(&node.id));
+            let _ = writeln!(xml, "      <data key=\"name\">{}</data>", xml_escape(&node.name));
+            let _ = writeln!(xml, "      <data key=\"category\">{}</data>", xml_escape(&node.category));
+            xml.push_str("    </node>\n");
+        }
+        for edge in &self.edges {
+            let _ = writeln!(
+                xml,
+                "    <edge source=\"{}\" target=\"{}\">",
+                xml_escape(&edge.source),
+                xml_escape(&edge.target)
+            );
+            let _ = writeln!(xml, "      <data key=\"weight\">{}</data>", edge.weight);
+            let _ = writeln!(xml, "      <data key=\"projects\">{}</data>", edge.projects);
+            let _ = writeln!(xml, "      <data key=\"experiences\">{}</data>", edge.experiences);
+            xml.push_str("    </edge>\n");
+        }
+        xml.push_str("  </graph>\n</graphml>\n");
+        xml
+    }
+}
+
+fn count_pairs<'a>(
+    counts: &mut BTreeMap<(&'a str, &'a str), usize>,
+    skills: &BTreeSet<&'a str>,
+) {
+    let skills: Vec<&str> = skills.iter().copied().collect();
+    for (i, a) in skills.iter().enumerate() {
+        for b in &skills[i + 1..] {
+            *counts.entry((a, b)).or_default() += 1;
+        }
+    }
+}
+
+pub fn build_skill_graph(resume: &Resume, index: &ResumeIndex) -> SkillGraph {
+    let known: BTreeSet<&str> = resume.skills.iter().map(|s| s.id.as_str()).collect();
+
+    let mut project_pairs = BTreeMap::new();
+    for project in &resume.projects {
+        let skills = project
+            .skills
+            .iter()
+            .map(String::as_str)
+            .filter(|id| known.contains(id))
+            .collect();
+        count_pairs(&mut project_pairs, &skills);
+    }
+
+    // An experience uses every skill of its projects.
+    let mut experience_pairs = BTreeMap::new();
+    for experience in &resume.experiences {
+        let skills = experience
+            .projects
+            .iter()
+            .filter_map(|id| index.project_lookup.get(id))
+            .flat_map(|project| &project.skills)
+            .map(String::as_str)
+            .filter(|id| known.contains(id))
+            .collect();
+        count_pairs(&mut experience_pairs, &skills);
+    }
+
+    let pairs: BTreeSet<(&str, &str)> = project_pairs
+        .keys()
+        .chain(experience_pairs.keys())
+        .copied()
+        .collect();
+    let edges = pairs
+        .into_iter()
+        .map(|pair| {
+            let projects = project_pairs.get(&pair).copied().unwrap_or_default();
+            let experiences = experience_pairs.get(&pair).copied().unwrap_or_default();
+            SkillEdge {
+                source: pair.0.to_string(),
+                target: pair.1.to_string(),
+                weight: projects + experiences,
+                projects,
+                experiences,
+            }
+        })
+        .collect();
+
+    let nodes = resume
+        .skills
+        .iter()
+        .map(|skill| SkillNode {
+            id: skill.id.clone(),
+            name: skill.name.clone(),
+            category: skill.category.clone(),
+        })
+        .collect();
+
+    SkillGraph { nodes, edges }
+}
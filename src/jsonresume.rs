@@ -0,0 +1,715 @@
+use crate::{
+    Certification, Education, Error, Experience, PersonalInfo, Proficiency, Project,
+    Publication, PublicationKind, Result, Resume, Skill, slugify,
+};
+use chrono::{DateTime, Months, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonResume {
+    #[serde(default)]
+    pub basics: Basics,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub work: Vec<Work>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub projects: Vec<JsonResumeProject>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skills: Vec<JsonResumeSkill>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub education: Vec<JsonResumeEducation>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub certificates: Vec<Certificate>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub publications: Vec<JsonResumePublication>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Basics {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub profiles: Vec<Profile>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+    #[serde(default)]
+    pub network: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Work {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub position: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub highlights: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonResumeProject {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub highlights: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonResumeEducation {
+    #[serde(default)]
+    pub institution: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub area: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub study_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub courses: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Certificate {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonResumePublication {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub publisher: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonResumeSkill {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+}
+
+#[derive(Default)]
+struct IdAllocator {
+    taken: HashSet<String>,
+}
+
+impl IdAllocator {
+    fn allocate(&mut self, name: &str, fallback: &str) -> String {
+        let base = match slugify(name) {
+            slug if slug.is_empty() => fallback.to_string(),
+            slug => slug,
+        };
+        let mut id = base.clone();
+        let mut n = 2;
+        while !self.taken.insert(id.clone()) {
+            id = format!("{}-{}", base, n);
+            n += 1;
+        }
+        id
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Bound {
+    Start,
+    End,
+}
+
+// Partial dates cover a whole year or month, so an end date is rounded to its last day.
+fn parse_date(value: &str, field: &str, bound: Bound) -> Result<DateTime<Utc>> {
+    let value = value.trim();
+    let parts: Vec<&str> = value.split('-').collect();
+    let first_of = |y: i32, m: u32| NaiveDate::from_ymd_opt(y, m, 1);
+    let parsed = match (parts.as_slice(), bound) {
+        ([y], Bound::Start) => y.parse().ok().and_then(|y| first_of(y, 1)),
+        ([y], Bound::End) => y.parse().ok().and_then(|y| NaiveDate::from_ymd_opt(y, 12, 31)),
+        ([y, m], _) => match (y.parse(), m.parse()) {
+            (Ok(y), Ok(m)) => match bound {
+                Bound::Start => first_of(y, m),
+                Bound::End => first_of(y, m)
+                    .and_then(|date| date.checked_add_months(Months::new(1)))
+                    .and_then(|date| date.pred_opt()),
+            },
+            _ => None,
+        },
+        _ => NaiveDate::parse_from_str(value, "%Y-%m-%d").ok(),
+    };
+    parsed
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|datetime| datetime.and_utc())
+        .ok_or_else(|| Error::Convert {
+            field: field.to_string(),
+            message: format!("invalid date '{}', expected YYYY, YYYY-MM or YYYY-MM-DD", value),
+        })
+}
+
+fn parse_optional_date(
+    value: &Option<String>,
+    field: &str,
+    bound: Bound,
+) -> Result<Option<DateTime<Utc>>> {
+    match value {
+        Some(date) if !date.trim().is_empty() => parse_date(date, field, bound).map(Some),
+        _ => Ok(None),
+    }
+}
+
+fn parse_required_date(value: &Option<String>, field: &str) -> Result<DateTime<Utc>> {
+    match value {
+        Some(value) => parse_date(value, field, Bound::Start),
+        None => Err(Error::Convert {
+            field: field.to_string(),
+            message: "missing date".to_string(),
+        }),
+    }
+}
+
+fn parse_level(level: &str) -> Option<Proficiency> {
+    match level.trim().to_lowercase().as_str() {
+        "beginner" | "novice" | "basic" => Some(Proficiency::Beginner),
+        "intermediate" => Some(Proficiency::Intermediate),
+        "advanced" => Some(Proficiency::Advanced),
+        "expert" | "master" => Some(Proficiency::Expert),
+        _ => None,
+    }
+}
+
+pub(crate) fn mentions(text: &str, term: &str) -> bool {
+    if term.is_empty() {
+        return false;
+    }
+    let is_word = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric());
+    text.match_indices(term).any(|(start, _)| {
+        !is_word(text[..start].chars().next_back())
+            && !is_word(text[start + term.len()..].chars().next())
+    })
+}
+
+fn format_date(date: &DateTime<Utc>) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    if value.trim().is_empty() {
+        None
+    } else {
+        Some(value.to_string())
+    }
+}
+
+fn describe(summary: Option<String>, highlights: &[String]) -> String {
+    let mut description = summary.unwrap_or_default();
+    for highlight in highlights {
+        if !description.is_empty() {
+            description.push('\n');
+        }
+        description.push_str(&format!("- {}", highlight));
+    }
+    description
+}
+
+struct SkillRegistry {
+    skills: Vec<Skill>,
+    by_name: HashMap<String, String>,
+    ids: IdAllocator,
+}
+
+impl SkillRegistry {
+    fn resolve(&mut self, name: &str, category: &str, proficiency: Option<Proficiency>) -> String {
+        let key = name.trim().to_lowercase();
+        if let Some(id) = self.by_name.get(&key) {
+            return id.clone();
+        }
+        let id = self.ids.allocate(name, "skill");
+        self.by_name.insert(key, id.clone());
+        self.skills.push(Skill {
+            id: id.clone(),
+            name: name.trim().to_string(),
+            skill_type: "skill".to_string(),
+            category: category.to_string(),
+            proficiency,
+            aliases: Vec::new(),
+        });
+        id
+    }
+
+    fn mentioned_in(&self, text: &str) -> Vec<String> {
+        let text = text.to_lowercase();
+        self.skills
+            .iter()
+            .filter(|skill| mentions(&text, &skill.name.to_lowercase()))
+            .map(|skill| skill.id.clone())
+            .collect()
+    }
+}
+
+impl JsonResume {
+    pub fn into_resume(self) -> Result<Resume> {
+        let basics = self.basics;
+        let location = basics
+            .location
+            .map(|l| {
+                [l.city, l.region, l.country_code]
+                    .into_iter()
+                    .flatten()
+                    .filter(|part| !part.trim().is_empty())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .unwrap_or_default();
+        let mut links = HashMap::new();
+        if let Some(url) = basics.url.filter(|url| !url.is_empty()) {
+            links.insert("website".to_string(), url);
+        }
+        for profile in basics.profiles {
+            if let Some(url) = profile.url.filter(|url| !url.is_empty()) {
+                links.insert(profile.network.to_lowercase(), url);
+            }
+        }
+        let info = PersonalInfo {
+            name: basics.name,
+            location,
+            phone_number: basics.phone.unwrap_or_default(),
+            email: basics.email.unwrap_or_default(),
+            links,
+        };
+
+        let mut registry = SkillRegistry {
+            skills: Vec::new(),
+            by_name: HashMap::new(),
+            ids: IdAllocator::default(),
+        };
+        for group in &self.skills {
+            let category = match slugify(&group.name) {
+                slug if slug.is_empty() => "other".to_string(),
+                slug => slug,
+            };
+            let proficiency = group.level.as_deref().and_then(parse_level);
+            if group.keywords.is_empty() {
+                registry.resolve(&group.name, &category, proficiency);
+            }
+            for keyword in &group.keywords {
+                registry.resolve(keyword, &category, proficiency);
+            }
+        }
+
+        let mut project_ids = IdAllocator::default();
+        let mut projects = Vec::new();
+        let mut entity_projects: HashMap<String, Vec<String>> = HashMap::new();
+        for project in self.projects {
+            let id = project_ids.allocate(&project.name, "project");
+            let skills: Vec<String> = project
+                .keywords
+                .iter()
+                .map(|keyword| registry.resolve(keyword, "other", None))
+                .collect();
+            let description = describe(project.description, &project.highlights);
+            if let Some(entity) = &project.entity {
+                entity_projects
+                    .entry(entity.trim().to_lowercase())
+                    .or_default()
+                    .push(id.clone());
+            }
+            projects.push(Project {
+                id,
+                title: project.name,
+                duration: None,
+                description,
+                skills,
+                aliases: Vec::new(),
+            });
+        }
+
+        for project in &mut projects {
+            for skill in registry.mentioned_in(&project.description) {
+                if !project.skills.contains(&skill) {
+                    project.skills.push(skill);
+                }
+            }
+        }
+
+        let mut experience_ids = IdAllocator::default();
+        let mut experiences = Vec::new();
+        for (i, work) in self.work.into_iter().enumerate() {
+            let id = experience_ids.allocate(&format!("{} {}", work.name, work.position), "work");
+            let start_date = match &work.start_date {
+                Some(date) => parse_date(date, &format!("work[{}].startDate", i), Bound::Start)?,
+                None => {
+                    return Err(Error::Convert {
+                        field: format!("work[{}].startDate", i),
+                        message: "missing start date".to_string(),
+                    });
+                }
+            };
+            let end_date =
+                parse_optional_date(&work.end_date, &format!("work[{}].endDate", i), Bound::End)?;
+
+            let mut experience_projects = entity_projects
+                .get(&work.name.trim().to_lowercase())
+                .cloned()
+                .unwrap_or_default();
+
+            let description = describe(work.summary, &work.highlights);
+            if !description.is_empty() {
+                let project_id = project_ids.allocate(&id, "project");
+                projects.push(Project {
+                    id: project_id.clone(),
+                    title: if work.position.is_empty() {
+                        work.name.clone()
+                    } else {
+                        work.position.clone()
+                    },
+                    duration: None,
+                    skills: registry.mentioned_in(&description),
+                    description,
+                    aliases: Vec::new(),
+                });
+                experience_projects.insert(0, project_id);
+            }
+
+            experiences.push(Experience {
+                id,
+                title: work.position,
+                employer: work.name,
+                start_date,
+                end_date,
+                projects: experience_projects,
+            });
+        }
+
+        let mut education_ids = IdAllocator::default();
+        let mut education = Vec::new();
+        for (i, entry) in self.education.into_iter().enumerate() {
+            let degree = entry.study_type.unwrap_or_default();
+            let mentioned = [entry.area.clone().unwrap_or_default(), entry.courses.join("\n")];
+            education.push(Education {
+                id: education_ids.allocate(&format!("{} {}", entry.institution, degree), "education"),
+                institution: entry.institution,
+                degree,
+                start_date: parse_optional_date(
+                    &entry.start_date,
+                    &format!("education[{}].startDate", i),
+                    Bound::Start,
+                )?,
+                end_date: parse_optional_date(
+                    &entry.end_date,
+                    &format!("education[{}].endDate", i),
+                    Bound::End,
+                )?,
+                field: entry.area,
+                grade: entry.score,
+                projects: Vec::new(),
+                skills: registry.mentioned_in(&mentioned.join("\n")),
+            });
+        }
+
+        let mut certification_ids = IdAllocator::default();
+        let mut certifications = Vec::new();
+        for (i, certificate) in self.certificates.into_iter().enumerate() {
+            let issued_date =
+                parse_required_date(&certificate.date, &format!("certificates[{}].date", i))?;
+            certifications.push(Certification {
+                id: certification_ids.allocate(&certificate.name, "certification"),
+                skills: registry.mentioned_in(&certificate.name),
+                name: certificate.name,
+                issuer: certificate.issuer.unwrap_or_default(),
+                issued_date,
+                expiry_date: None,
+                credential_id: None,
+                url: certificate.url,
+            });
+        }
+
+        // JSON Resume has no notion of talks or patents, so everything imports as a paper.
+        let mut publication_ids = IdAllocator::default();
+        let mut publications = Vec::new();
+        for (i, publication) in self.publications.into_iter().enumerate() {
+            let date = parse_required_date(
+                &publication.release_date,
+                &format!("publications[{}].releaseDate", i),
+            )?;
+            let mentioned = [publication.name.clone(), publication.summary.clone().unwrap_or_default()];
+            publications.push(Publication {
+                id: publication_ids.allocate(&publication.name, "publication"),
+                skills: registry.mentioned_in(&mentioned.join("\n")),
+                title: publication.name,
+                kind: PublicationKind::Paper,
+                venue: publication.publisher,
+                date,
+                co_authors: Vec::new(),
+                url: publication.url,
+            });
+        }
+
+        Ok(Resume {
+            info,
+            experiences,
+            projects,
+            skills: registry.skills,
+            education,
+            certifications,
+            publications,
+        })
+    }
+}
+
+impl From<&Resume> for JsonResume {
+    fn from(resume: &Resume) -> Self {
+        let info = &resume.info;
+        let parts: Vec<&str> = info
+            .location
+            .split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .collect();
+        let location = match parts.as_slice() {
+            [] => None,
+            [city] => Some(Location {
+                city: Some(city.to_string()),
+                ..Default::default()
+            }),
+            [city, region] => Some(Location {
+                city: Some(city.to_string()),
+                region: Some(region.to_string()),
+                ..Default::default()
+            }),
+            [city, region, country_code] => Some(Location {
+                city: Some(city.to_string()),
+                region: Some(region.to_string()),
+                country_code: Some(country_code.to_string()),
+                ..Default::default()
+            }),
+            _ => Some(Location {
+                address: Some(info.location.clone()),
+                ..Default::default()
+            }),
+        };
+
+        let mut networks: Vec<&String> = info.links.keys().filter(|k| *k != "website").collect();
+        networks.sort();
+        let basics = Basics {
+            name: info.name.clone(),
+            label: None,
+            email: non_empty(&info.email),
+            phone: non_empty(&info.phone_number),
+            url: info.links.get("website").cloned(),
+            summary: None,
+            location,
+            profiles: networks
+                .into_iter()
+                .map(|network| Profile {
+                    network: network.clone(),
+                    username: None,
+                    url: Some(info.links[network].clone()),
+                })
+                .collect(),
+        };
+
+        let skill_names: HashMap<&str, &str> = resume
+            .skills
+            .iter()
+            .map(|s| (s.id.as_str(), s.name.as_str()))
+            .collect();
+
+        let work = resume
+            .experiences
+            .iter()
+            .map(|experience| Work {
+                name: experience.employer.clone(),
+                position: experience.title.clone(),
+                url: None,
+                start_date: Some(format_date(&experience.start_date)),
+                end_date: experience.end_date.as_ref().map(format_date),
+                summary: None,
+                highlights: Vec::new(),
+            })
+            .collect();
+
+        let projects = resume
+            .projects
+            .iter()
+            .map(|project| JsonResumeProject {
+                name: project.title.clone(),
+                description: non_empty(&project.description),
+                highlights: Vec::new(),
+                keywords: project
+                    .skills
+                    .iter()
+                    .filter_map(|id| skill_names.get(id.as_str()))
+                    .map(|name| name.to_string())
+                    .collect(),
+                start_date: None,
+                end_date: None,
+                url: None,
+                roles: Vec::new(),
+                entity: resume
+                    .experiences
+                    .iter()
+                    .find(|e| e.projects.contains(&project.id))
+                    .map(|e| e.employer.clone()),
+            })
+            .collect();
+
+        let mut skills: Vec<JsonResumeSkill> = Vec::new();
+        for skill in &resume.skills {
+            match skills.iter_mut().find(|group| group.name == skill.category) {
+                Some(group) => group.keywords.push(skill.name.clone()),
+                None => skills.push(JsonResumeSkill {
+                    name: skill.category.clone(),
+                    level: None,
+                    keywords: vec![skill.name.clone()],
+                }),
+            }
+        }
+        // A group only has a level when every skill in it declares the same proficiency.
+        for group in &mut skills {
+            let mut levels = resume
+                .skills
+                .iter()
+                .filter(|skill| skill.category == group.name)
+                .map(|skill| skill.proficiency);
+            let first = levels.next().flatten();
+            if levels.all(|level| level == first) {
+                group.level = first.map(|level| level.as_str().to_string());
+            }
+        }
+
+        let education = resume
+            .education
+            .iter()
+            .map(|entry| JsonResumeEducation {
+                institution: entry.institution.clone(),
+                url: None,
+                area: entry.field.clone(),
+                study_type: non_empty(&entry.degree),
+                start_date: entry.start_date.as_ref().map(format_date),
+                end_date: entry.end_date.as_ref().map(format_date),
+                score: entry.grade.clone(),
+                courses: Vec::new(),
+            })
+            .collect();
+
+        let certificates = resume
+            .certifications
+            .iter()
+            .map(|certification| Certificate {
+                name: certification.name.clone(),
+                date: Some(format_date(&certification.issued_date)),
+                issuer: non_empty(&certification.issuer),
+                url: certification.url.clone(),
+            })
+            .collect();
+
+        let publications = resume
+            .publications
+            .iter()
+            .map(|publication| JsonResumePublication {
+                name: publication.title.clone(),
+                publisher: publication.venue.clone(),
+                release_date: Some(format_date(&publication.date)),
+                url: publication.url.clone(),
+                summary: None,
+            })
+            .collect();
+
+        JsonResume {
+            basics,
+            work,
+            projects,
+            skills,
+            education,
+            certificates,
+            publications,
+        }
+    }
+}
+
+pub fn is_json_resume(value: &serde_json::Value) -> bool {
+    value.get("basics").is_some() && value.get("resume").is_none()
+}
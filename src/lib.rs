@@ -1,21 +1,126 @@
 use chrono::{DateTime, Utc};
+use schema::structured_content;
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::{HashMap, HashSet},
+    collections::HashMap,
     fs,
+    path::Path,
+};
+
+mod aliases;
+mod clusters;
+mod custom_tools;
+mod dates;
+mod error;
+mod graph;
+pub mod jsonresume;
+mod loader;
+mod markdown;
+mod matching;
+mod prompts;
+mod schema;
+mod search;
+mod similarity;
+mod timeline;
+mod tools;
+mod validation;
+
+pub use aliases::{AliasMap, alias_variants, build_alias_map};
+pub use clusters::{SkillCluster, find_skill_clusters};
+pub use custom_tools::{
+    ArgumentDomain, CustomTool, CustomToolArgument, CustomToolFilter, CustomToolTarget,
+    check_custom_tools,
+};
+pub use error::{Error, Result};
+pub use graph::{RelatedSkill, SkillEdge, SkillGraph, SkillNode, build_skill_graph};
+pub use jsonresume::JsonResume;
+pub use loader::{ConfigFormat, Origins, ResumeFragment, load_config, load_json_resume};
+pub use markdown::parse_markdown_project;
+pub use matching::{
+    EntityMatch, EntityVector, JobMatch, MatchArtifacts, TermVectors, Vocabulary,
+    build_match_artifacts, match_job_description,
+};
+pub use prompts::{
+    PromptArgument, PromptTemplate, RESUME_VARIABLES, check_prompts, default_prompts, render_prompt,
+    resume_variables,
+};
+pub use schema::{JsonSchema, output_schema};
+pub use search::{SearchHit, SearchIndex, SearchResults, build_search_index, tokenize};
+pub use similarity::{RelatedProject, TermVector, TfIdf, cosine, jaccard, related_projects};
+pub use timeline::{
+    CareerGap, CareerGaps, CareerOverlap, Interval, LastUsed, SkillExperienceSummary,
+    TimelineEntry, active_years, career_gaps, career_timeline, experiences_active_in_year,
+    merge_intervals, skill_experience_summaries, skills_by_recency,
+};
+pub use tools::{StaticTool, ToolContext, builtin_tools};
+pub use validation::{
+    IssueKind, Severity, ValidationIssue, ValidationReport, validate, validate_with_origins,
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     pub resume: Resume,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+    #[serde(default)]
+    pub options: GeneratorOptions,
+    #[serde(default = "default_prompts")]
+    pub prompts: Vec<PromptTemplate>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub custom_tools: Vec<CustomTool>,
+    #[serde(skip)]
+    pub project_markdown: HashMap<String, String>,
+    #[serde(skip)]
+    pub origins: Origins,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratorOptions {
+    #[serde(default = "default_gap_threshold_days")]
+    pub gap_threshold_days: i64,
+    #[serde(default = "default_cluster_min_support")]
+    pub cluster_min_support: usize,
+    #[serde(default = "default_cluster_max_size")]
+    pub cluster_max_size: usize,
+}
+
+fn default_gap_threshold_days() -> i64 {
+    90
+}
+
+fn default_cluster_min_support() -> usize {
+    2
+}
+
+fn default_cluster_max_size() -> usize {
+    5
+}
+
+impl Default for GeneratorOptions {
+    fn default() -> Self {
+        GeneratorOptions {
+            gap_threshold_days: default_gap_threshold_days(),
+            cluster_min_support: default_cluster_min_support(),
+            cluster_max_size: default_cluster_max_size(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Resume {
     pub info: PersonalInfo,
+    #[serde(default)]
     pub experiences: Vec<Experience>,
+    #[serde(default)]
     pub projects: Vec<Project>,
+    #[serde(default)]
     pub skills: Vec<Skill>,
+    #[serde(default)]
+    pub education: Vec<Education>,
+    #[serde(default)]
+    pub certifications: Vec<Certification>,
+    #[serde(default)]
+    pub publications: Vec<Publication>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -32,7 +137,9 @@ pub struct Experience {
     pub id: String,
     pub title: String,
     pub employer: String,
+    #[serde(deserialize_with = "dates::deserialize")]
     pub start_date: DateTime<Utc>,
+    #[serde(default, deserialize_with = "dates::option::deserialize")]
     pub end_date: Option<DateTime<Utc>>,
     pub projects: Vec<String>,
 }
@@ -44,6 +151,8 @@ pub struct Project {
     pub duration: Option<String>,
     pub description: String,
     pub skills: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -53,6 +162,116 @@ pub struct Skill {
     #[serde(rename = "type")]
     pub skill_type: String,
     pub category: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proficiency: Option<Proficiency>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Proficiency {
+    Beginner,
+    Intermediate,
+    Advanced,
+    Expert,
+}
+
+impl Proficiency {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Proficiency::Beginner => "beginner",
+            Proficiency::Intermediate => "intermediate",
+            Proficiency::Advanced => "advanced",
+            Proficiency::Expert => "expert",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Education {
+    pub id: String,
+    pub institution: String,
+    pub degree: String,
+    #[serde(default)]
+    pub field: Option<String>,
+    #[serde(default, deserialize_with = "dates::option::deserialize")]
+    pub start_date: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "dates::option::deserialize")]
+    pub end_date: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub grade: Option<String>,
+    #[serde(default)]
+    pub projects: Vec<String>,
+    #[serde(default)]
+    pub skills: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Certification {
+    pub id: String,
+    pub name: String,
+    pub issuer: String,
+    #[serde(deserialize_with = "dates::deserialize")]
+    pub issued_date: DateTime<Utc>,
+    #[serde(default, deserialize_with = "dates::option::deserialize")]
+    pub expiry_date: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub credential_id: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub skills: Vec<String>,
+}
+
+impl Certification {
+    pub fn is_active_at(&self, at: DateTime<Utc>) -> bool {
+        self.issued_date <= at && self.expiry_date.is_none_or(|expiry| expiry > at)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PublicationKind {
+    Paper,
+    Talk,
+    Patent,
+    Blog,
+}
+
+impl PublicationKind {
+    pub const ALL: [PublicationKind; 4] = [
+        PublicationKind::Paper,
+        PublicationKind::Talk,
+        PublicationKind::Patent,
+        PublicationKind::Blog,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PublicationKind::Paper => "paper",
+            PublicationKind::Talk => "talk",
+            PublicationKind::Patent => "patent",
+            PublicationKind::Blog => "blog",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Publication {
+    pub id: String,
+    pub title: String,
+    pub kind: PublicationKind,
+    #[serde(default)]
+    pub venue: Option<String>,
+    #[serde(deserialize_with = "dates::deserialize")]
+    pub date: DateTime<Utc>,
+    #[serde(default)]
+    pub co_authors: Vec<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub skills: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -64,12 +283,34 @@ pub struct MCPResource {
     pub mime_type: String,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MCPResourceTemplate {
+    #[serde(rename = "uriTemplate")]
+    pub uri_template: String,
+    pub name: String,
+    pub description: String,
+    #[serde(rename = "mimeType")]
+    pub mime_type: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MCPPrompt {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub description: String,
+    #[serde(default)]
+    pub arguments: Vec<PromptArgument>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MCPToolSchema {
     pub name: String,
     pub description: String,
     #[serde(rename = "inputSchema")]
     pub input_schema: serde_json::Value,
+    #[serde(rename = "outputSchema", default, skip_serializing_if = "Option::is_none")]
+    pub output_schema: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -84,7 +325,11 @@ pub struct MCPManifest {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MCPCapabilities {
     pub resources: Vec<MCPResource>,
+    #[serde(rename = "resourceTemplates", default)]
+    pub resource_templates: Vec<MCPResourceTemplate>,
     pub tools: Vec<MCPToolSchema>,
+    #[serde(default)]
+    pub prompts: Vec<MCPPrompt>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -104,6 +349,32 @@ pub struct MCPResourceContent {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MCPToolResult {
     pub content: Vec<MCPToolContent>,
+    #[serde(rename = "structuredContent", default, skip_serializing_if = "Option::is_none")]
+    pub structured_content: Option<serde_json::Value>,
+}
+
+impl MCPToolResult {
+    pub fn new<T: Serialize>(value: &T) -> Result<Self> {
+        Ok(MCPToolResult {
+            content: vec![MCPToolContent {
+                content_type: "text".to_string(),
+                text: serde_json::to_string_pretty(value)?,
+            }],
+            structured_content: Some(structured_content(value)?),
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MCPPromptResult {
+    pub description: String,
+    pub messages: Vec<MCPPromptMessage>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MCPPromptMessage {
+    pub role: String,
+    pub content: MCPToolContent,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -118,15 +389,27 @@ pub struct ResumeIndex {
     pub skill_to_projects: HashMap<String, Vec<String>>,
     pub skill_to_experiences: HashMap<String, Vec<String>>,
     pub project_to_experiences: HashMap<String, Vec<String>>,
+    pub skill_to_education: HashMap<String, Vec<String>>,
+    pub skill_to_certifications: HashMap<String, Vec<String>>,
+    pub skill_to_publications: HashMap<String, Vec<String>>,
     pub experience_lookup: HashMap<String, Experience>,
     pub project_lookup: HashMap<String, Project>,
     pub skill_lookup: HashMap<String, Skill>,
+    pub education_lookup: HashMap<String, Education>,
+    pub certification_lookup: HashMap<String, Certification>,
+    pub publication_lookup: HashMap<String, Publication>,
 }
 
 pub struct StaticGenerator {
     pub resume: Resume,
     pub index: ResumeIndex,
     pub output_dir: String,
+    pub project_markdown: HashMap<String, String>,
+    pub generated_at: DateTime<Utc>,
+    pub options: GeneratorOptions,
+    pub prompts: Vec<PromptTemplate>,
+    pub tools: Vec<Box<dyn StaticTool>>,
+    pub origins: Origins,
 }
 
 impl StaticGenerator {
@@ -136,26 +419,121 @@ impl StaticGenerator {
             resume,
             index,
             output_dir,
+            project_markdown: HashMap::new(),
+            generated_at: Utc::now(),
+            options: GeneratorOptions::default(),
+            prompts: default_prompts(),
+            tools: builtin_tools(),
+            origins: Origins::default(),
         }
     }
 
-    pub fn generate(&self) -> Result<(), Box<dyn std::error::Error>> {
-        fs::create_dir_all(&self.output_dir)?;
-        fs::create_dir_all(format!("{}/resources", self.output_dir))?;
-        fs::create_dir_all(format!("{}/tools", self.output_dir))?;
-        fs::create_dir_all(format!("{}/indexes", self.output_dir))?;
+    pub fn with_options(mut self, options: GeneratorOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn with_prompts(mut self, prompts: Vec<PromptTemplate>) -> Self {
+        self.prompts = prompts;
+        self
+    }
+
+    pub fn with_tool(mut self, tool: impl StaticTool + 'static) -> Self {
+        self.register_tool(Box::new(tool));
+        self
+    }
+
+    // A tool named like an already registered one replaces it.
+    pub fn register_tool(&mut self, tool: Box<dyn StaticTool>) {
+        match self.tools.iter_mut().find(|existing| existing.name() == tool.name()) {
+            Some(existing) => *existing = tool,
+            None => self.tools.push(tool),
+        }
+    }
+
+    pub fn with_custom_tools(mut self, tools: Vec<CustomTool>) -> Self {
+        for tool in tools {
+            self.register_tool(Box::new(tool));
+        }
+        self
+    }
+
+    pub fn tool_context(&self) -> ToolContext<'_> {
+        ToolContext::new(&self.resume, &self.index, &self.options, self.generated_at)
+    }
+
+    pub fn with_generated_at(mut self, generated_at: DateTime<Utc>) -> Self {
+        self.generated_at = generated_at;
+        self
+    }
+
+    pub fn with_project_markdown(mut self, project_markdown: HashMap<String, String>) -> Self {
+        self.project_markdown = project_markdown;
+        self
+    }
+
+    pub fn with_origins(mut self, origins: Origins) -> Self {
+        self.origins = origins;
+        self
+    }
+
+    fn markdown_project_ids(&self) -> Vec<&String> {
+        let mut ids: Vec<&String> = self
+            .project_markdown
+            .keys()
+            .filter(|id| self.index.project_lookup.contains_key(*id))
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    fn career_gaps(&self) -> CareerGaps {
+        career_gaps(&self.resume, self.generated_at, self.options.gap_threshold_days)
+    }
+
+    fn skill_experience_summaries(&self) -> Vec<SkillExperienceSummary> {
+        skill_experience_summaries(&self.resume, &self.index, self.generated_at)
+    }
+
+    pub fn generate(&self) -> Result<()> {
+        let report = validate_with_origins(&self.resume, &self.origins);
+        for warning in report.warnings() {
+            eprintln!("warning: {}", warning);
+        }
+        if report.has_errors() {
+            return Err(report.into());
+        }
+        check_prompts(&self.prompts)?;
+        let context = self.tool_context();
+        for tool in &self.tools {
+            tool.check(&context)?;
+        }
+        let career = self.career_gaps();
+        for gap in &career.gaps {
+            eprintln!("warning: {}", gap);
+        }
+        for overlap in &career.overlaps {
+            eprintln!("warning: {}", overlap);
+        }
+
+        create_dir_all(&self.output_dir)?;
+        create_dir_all(&format!("{}/resources", self.output_dir))?;
+        create_dir_all(&format!("{}/tools", self.output_dir))?;
+        create_dir_all(&format!("{}/indexes", self.output_dir))?;
+        create_dir_all(&format!("{}/prompts", self.output_dir))?;
 
         self.generate_manifest()?;
         self.generate_resources()?;
         self.generate_tool_results()?;
         self.generate_indexes()?;
+        self.generate_prompts()?;
 
         println!("Static MCP site generated in: {}", self.output_dir);
         Ok(())
     }
 
-    pub fn generate_manifest(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let manifest = MCPManifest {
+    pub fn generate_manifest(&self) -> Result<()> {
+        let mut manifest = MCPManifest {
             protocol_version: "2025-06-18".to_string(),
             capabilities: MCPCapabilities {
                 resources: vec![
@@ -183,103 +561,98 @@ impl StaticGenerator {
                         description: "Complete list of skills".to_string(),
                         mime_type: "application/json".to_string(),
                     },
-                ],
-                tools: vec![
-                    MCPToolSchema {
-                        name: "get_skills_for_project".to_string(),
-                        description: "Get all skills used in a specific project".to_string(),
-                        input_schema: serde_json::json!({
-                            "type": "object",
-                            "properties": {
-                                "project_id": {"type": "string", "description": "Project ID"}
-                            },
-                            "required": ["project_id"]
-                        }),
+                    MCPResource {
+                        uri: "resume://education".to_string(),
+                        name: "All Education".to_string(),
+                        description: "Complete list of degrees and other education".to_string(),
+                        mime_type: "application/json".to_string(),
                     },
-                    MCPToolSchema {
-                        name: "get_projects_using_skill".to_string(),
-                        description: "Get all projects that use a specific skill".to_string(),
-                        input_schema: serde_json::json!({
-                            "type": "object",
-                            "properties": {
-                                "skill_id": {"type": "string", "description": "Skill ID"}
-                            },
-                            "required": ["skill_id"]
-                        }),
+                    MCPResource {
+                        uri: "resume://certifications".to_string(),
+                        name: "All Certifications".to_string(),
+                        description: "Complete list of certifications and licenses".to_string(),
+                        mime_type: "application/json".to_string(),
                     },
-                    MCPToolSchema {
-                        name: "get_experiences_using_skill".to_string(),
-                        description: "Get all experiences that involve a specific skill".to_string(),
-                        input_schema: serde_json::json!({
-                            "type": "object",
-                            "properties": {
-                                "skill_id": {"type": "string", "description": "Skill ID"}
-                            },
-                            "required": ["skill_id"]
-                        }),
+                    MCPResource {
+                        uri: "resume://publications".to_string(),
+                        name: "All Publications".to_string(),
+                        description: "Complete list of papers, talks, patents and blog posts".to_string(),
+                        mime_type: "application/json".to_string(),
                     },
-                    MCPToolSchema {
-                        name: "get_shared_skills".to_string(),
-                        description: "Get skills shared between two projects".to_string(),
-                        input_schema: serde_json::json!({
-                            "type": "object",
-                            "properties": {
-                                "project_a": {"type": "string", "description": "First project ID"},
-                                "project_b": {"type": "string", "description": "Second project ID"}
-                            },
-                            "required": ["project_a", "project_b"]
-                        }),
+                    MCPResource {
+                        uri: "resume://aliases".to_string(),
+                        name: "Skill and Project Aliases".to_string(),
+                        description: "Map of alternative names to skill and project ids, accepted by every tool that takes a skill_id or project_id".to_string(),
+                        mime_type: "application/json".to_string(),
                     },
-                    MCPToolSchema {
-                        name: "find_skill_clusters".to_string(),
-                        description: "Find clusters of skills that frequently appear together".to_string(),
-                        input_schema: serde_json::json!({
-                            "type": "object",
-                            "properties": {},
-                            "required": []
-                        }),
+                    MCPResource {
+                        uri: "resume://skill_graph".to_string(),
+                        name: "Skill Co-occurrence Graph".to_string(),
+                        description: "Skills as nodes, linked by the number of projects and experiences using both".to_string(),
+                        mime_type: "application/json".to_string(),
                     },
-                    MCPToolSchema {
-                        name: "get_basic_info".to_string(),
-                        description: "Get basic personal information".to_string(),
-                        input_schema: serde_json::json!({
-                            "type": "object",
-                            "properties": {},
-                            "required": []
-                        }),
+                    MCPResource {
+                        uri: "resume://skill_graph/dot".to_string(),
+                        name: "Skill Co-occurrence Graph (DOT)".to_string(),
+                        description: "The skill co-occurrence graph in Graphviz DOT format".to_string(),
+                        mime_type: "text/vnd.graphviz".to_string(),
                     },
-                    MCPToolSchema {
-                        name: "get_resume_indexes".to_string(),
-                        description: "Get index-level details for skills, projects, and experiences".to_string(),
-                        input_schema: serde_json::json!({
-                            "type": "object",
-                            "properties": {},
-                            "required": []
-                        }),
+                    MCPResource {
+                        uri: "resume://skill_graph/graphml".to_string(),
+                        name: "Skill Co-occurrence Graph (GraphML)".to_string(),
+                        description: "The skill co-occurrence graph in GraphML format".to_string(),
+                        mime_type: "application/graphml+xml".to_string(),
                     },
-                    MCPToolSchema {
-                        name: "get_experience_details".to_string(),
-                        description: "Get detailed information about a specific experience".to_string(),
-                        input_schema: serde_json::json!({
-                            "type": "object",
-                            "properties": {
-                                "experience_id": {"type": "string", "description": "Experience ID"}
-                            },
-                            "required": ["experience_id"]
-                        }),
+                    MCPResource {
+                        uri: "resume://match/vocabulary".to_string(),
+                        name: "Job Matching Vocabulary".to_string(),
+                        description: "IDF weight of every term and the phrases that identify each skill, used by match_job_description".to_string(),
+                        mime_type: "application/json".to_string(),
+                    },
+                    MCPResource {
+                        uri: "resume://match/vectors".to_string(),
+                        name: "Job Matching Term Vectors".to_string(),
+                        description: "Normalized TF-IDF term vector and skills of every project and experience, used by match_job_description".to_string(),
+                        mime_type: "application/json".to_string(),
+                    },
+                    MCPResource {
+                        uri: "resume://jsonresume".to_string(),
+                        name: "JSON Resume".to_string(),
+                        description: "Complete resume in the JSON Resume (jsonresume.org) schema".to_string(),
+                        mime_type: "application/json".to_string(),
+                    },
+                ],
+                resource_templates: vec![
+                    MCPResourceTemplate {
+                        uri_template: "resume://experiences/{id}".to_string(),
+                        name: "Experience".to_string(),
+                        description: "A single work experience by ID".to_string(),
+                        mime_type: "application/json".to_string(),
+                    },
+                    MCPResourceTemplate {
+                        uri_template: "resume://projects/{id}".to_string(),
+                        name: "Project".to_string(),
+                        description: "A single project by ID".to_string(),
+                        mime_type: "application/json".to_string(),
                     },
-                    MCPToolSchema {
-                        name: "get_project_details".to_string(),
-                        description: "Get detailed information about a specific project".to_string(),
-                        input_schema: serde_json::json!({
-                            "type": "object",
-                            "properties": {
-                                "project_id": {"type": "string", "description": "Project ID"}
-                            },
-                            "required": ["project_id"]
-                        }),
+                    MCPResourceTemplate {
+                        uri_template: "resume://skills/{id}".to_string(),
+                        name: "Skill".to_string(),
+                        description: "A single skill by ID, with its years of experience".to_string(),
+                        mime_type: "application/json".to_string(),
                     },
                 ],
+                tools: self.tools.iter().map(|tool| tool.schema()).collect(),
+                prompts: self
+                    .prompts
+                    .iter()
+                    .map(|prompt| MCPPrompt {
+                        name: prompt.name.clone(),
+                        title: prompt.title.clone(),
+                        description: prompt.description.clone(),
+                        arguments: prompt.arguments.clone(),
+                    })
+                    .collect(),
             },
             server_info: MCPServerInfo {
                 name: "static-resume-mcp".to_string(),
@@ -287,19 +660,28 @@ impl StaticGenerator {
             },
         };
 
+        for id in self.markdown_project_ids() {
+            manifest.capabilities.resources.push(MCPResource {
+                uri: format!("resume://markdown/{}", id),
+                name: format!("{} (Markdown)", self.index.project_lookup[id].title),
+                description: format!("Markdown source of project '{}'", id),
+                mime_type: "text/markdown".to_string(),
+            });
+        }
+
         let manifest_json = serde_json::to_string_pretty(&manifest)?;
-        fs::write(format!("{}/mcp.json", self.output_dir), manifest_json)?;
+        write(&format!("{}/mcp.json", self.output_dir), manifest_json)?;
         Ok(())
     }
 
-    pub fn generate_resources(&self) -> Result<(), Box<dyn std::error::Error>> {
+    pub fn generate_resources(&self) -> Result<()> {
         let info_content = MCPResourceContent {
             uri: "resume://info".to_string(),
             mime_type: "application/json".to_string(),
             text: serde_json::to_string_pretty(&self.resume.info)?,
         };
-        fs::write(
-            format!("{}/resources/info.json", self.output_dir),
+        write(
+            &format!("{}/resources/info.json", self.output_dir),
             serde_json::to_string_pretty(&info_content)?,
         )?;
 
@@ -308,8 +690,8 @@ impl StaticGenerator {
             mime_type: "application/json".to_string(),
             text: serde_json::to_string_pretty(&self.resume.experiences)?,
         };
-        fs::write(
-            format!("{}/resources/experiences.json", self.output_dir),
+        write(
+            &format!("{}/resources/experiences.json", self.output_dir),
             serde_json::to_string_pretty(&experiences_content)?,
         )?;
 
@@ -318,235 +700,225 @@ impl StaticGenerator {
             mime_type: "application/json".to_string(),
             text: serde_json::to_string_pretty(&self.resume.projects)?,
         };
-        fs::write(
-            format!("{}/resources/projects.json", self.output_dir),
+        write(
+            &format!("{}/resources/projects.json", self.output_dir),
             serde_json::to_string_pretty(&projects_content)?,
         )?;
 
+        let summaries = self.skill_experience_summaries();
         let skills_content = MCPResourceContent {
             uri: "resume://skills".to_string(),
             mime_type: "application/json".to_string(),
-            text: serde_json::to_string_pretty(&self.resume.skills)?,
+            text: serde_json::to_string_pretty(&summaries)?,
         };
-        fs::write(
-            format!("{}/resources/skills.json", self.output_dir),
+        write(
+            &format!("{}/resources/skills.json", self.output_dir),
             serde_json::to_string_pretty(&skills_content)?,
         )?;
 
-        Ok(())
-    }
+        self.generate_entity_resources(
+            "experiences",
+            self.resume.experiences.iter().map(|e| (e.id.as_str(), e)),
+        )?;
+        self.generate_entity_resources(
+            "projects",
+            self.resume.projects.iter().map(|p| (p.id.as_str(), p)),
+        )?;
+        self.generate_entity_resources(
+            "skills",
+            summaries.iter().map(|s| (s.skill.id.as_str(), s)),
+        )?;
 
-    pub fn generate_tool_results(&self) -> Result<(), Box<dyn std::error::Error>> {
-        fs::create_dir_all(format!("{}/tools/get_skills_for_project", self.output_dir))?;
-        fs::create_dir_all(format!("{}/tools/get_projects_using_skill", self.output_dir))?;
-        fs::create_dir_all(format!("{}/tools/get_experiences_using_skill", self.output_dir))?;
-        fs::create_dir_all(format!("{}/tools/get_shared_skills", self.output_dir))?;
-        fs::create_dir_all(format!("{}/tools/get_basic_info", self.output_dir))?;
-        fs::create_dir_all(format!("{}/tools/get_resume_indexes", self.output_dir))?;
-        fs::create_dir_all(format!("{}/tools/get_experience_details", self.output_dir))?;
-        fs::create_dir_all(format!("{}/tools/get_project_details", self.output_dir))?;
-
-        for project in &self.resume.projects {
-            let skills: Vec<&Skill> = project
-                .skills
-                .iter()
-                .filter_map(|id| self.index.skill_lookup.get(id))
-                .collect();
-            
-            let result = MCPToolResult {
-                content: vec![MCPToolContent {
-                    content_type: "text".to_string(),
-                    text: serde_json::to_string_pretty(&skills)?,
-                }],
-            };
-            
-            fs::write(
-                format!("{}/tools/get_skills_for_project/{}.json", self.output_dir, project.id),
-                serde_json::to_string_pretty(&result)?,
-            )?;
-        }
+        let education_content = MCPResourceContent {
+            uri: "resume://education".to_string(),
+            mime_type: "application/json".to_string(),
+            text: serde_json::to_string_pretty(&self.resume.education)?,
+        };
+        write(
+            &format!("{}/resources/education.json", self.output_dir),
+            serde_json::to_string_pretty(&education_content)?,
+        )?;
 
-        for skill in &self.resume.skills {
-            let project_ids = self
-                .index
-                .skill_to_projects
-                .get(&skill.id)
-                .cloned()
-                .unwrap_or_default();
-            
-            let projects: Vec<&Project> = project_ids
-                .iter()
-                .filter_map(|id| self.index.project_lookup.get(id))
-                .collect();
-            
-            let result = MCPToolResult {
-                content: vec![MCPToolContent {
-                    content_type: "text".to_string(),
-                    text: serde_json::to_string_pretty(&projects)?,
-                }],
-            };
-            
-            fs::write(
-                format!("{}/tools/get_projects_using_skill/{}.json", self.output_dir, skill.id),
-                serde_json::to_string_pretty(&result)?,
-            )?;
-        }
+        let certifications_content = MCPResourceContent {
+            uri: "resume://certifications".to_string(),
+            mime_type: "application/json".to_string(),
+            text: serde_json::to_string_pretty(&self.resume.certifications)?,
+        };
+        write(
+            &format!("{}/resources/certifications.json", self.output_dir),
+            serde_json::to_string_pretty(&certifications_content)?,
+        )?;
 
-        for skill in &self.resume.skills {
-            let experience_ids = self
-                .index
-                .skill_to_experiences
-                .get(&skill.id)
-                .cloned()
-                .unwrap_or_default();
-            
-            let experiences: Vec<&Experience> = experience_ids
-                .iter()
-                .filter_map(|id| self.index.experience_lookup.get(id))
-                .collect();
-            
-            let result = MCPToolResult {
-                content: vec![MCPToolContent {
-                    content_type: "text".to_string(),
-                    text: serde_json::to_string_pretty(&experiences)?,
-                }],
-            };
-            
-            fs::write(
-                format!("{}/tools/get_experiences_using_skill/{}.json", self.output_dir, skill.id),
-                serde_json::to_string_pretty(&result)?,
-            )?;
-        }
+        let publications_content = MCPResourceContent {
+            uri: "resume://publications".to_string(),
+            mime_type: "application/json".to_string(),
+            text: serde_json::to_string_pretty(&self.resume.publications)?,
+        };
+        write(
+            &format!("{}/resources/publications.json", self.output_dir),
+            serde_json::to_string_pretty(&publications_content)?,
+        )?;
 
-        for (i, project_a) in self.resume.projects.iter().enumerate() {
-            fs::create_dir_all(format!("{}/tools/get_shared_skills/{}", self.output_dir, project_a.id))?;
-            
-            for project_b in self.resume.projects.iter().skip(i + 1) {
-                let skills_a: HashSet<String> = project_a.skills.iter().cloned().collect();
-                let skills_b: HashSet<String> = project_b.skills.iter().cloned().collect();
-                
-                let shared: Vec<&Skill> = skills_a
-                    .intersection(&skills_b)
-                    .filter_map(|id| self.index.skill_lookup.get(id))
-                    .collect();
-                
-                let result = MCPToolResult {
-                    content: vec![MCPToolContent {
-                        content_type: "text".to_string(),
-                        text: serde_json::to_string_pretty(&shared)?,
-                    }],
-                };
-                
-                fs::write(
-                    format!("{}/tools/get_shared_skills/{}/{}.json", self.output_dir, project_a.id, project_b.id),
-                    serde_json::to_string_pretty(&result)?,
-                )?;
-                
-                fs::create_dir_all(format!("{}/tools/get_shared_skills/{}", self.output_dir, project_b.id))?;
-                fs::write(
-                    format!("{}/tools/get_shared_skills/{}/{}.json", self.output_dir, project_b.id, project_a.id),
-                    serde_json::to_string_pretty(&result)?,
-                )?;
-            }
-        }
+        let aliases_content = MCPResourceContent {
+            uri: "resume://aliases".to_string(),
+            mime_type: "application/json".to_string(),
+            text: serde_json::to_string_pretty(&build_alias_map(&self.resume))?,
+        };
+        write(
+            &format!("{}/resources/aliases.json", self.output_dir),
+            serde_json::to_string_pretty(&aliases_content)?,
+        )?;
 
-        let mut skill_combinations = HashMap::new();
-        
-        for project in &self.resume.projects {
-            if project.skills.len() > 1 {
-                for i in 0..project.skills.len() {
-                    for j in (i + 1)..project.skills.len() {
-                        let mut pair = vec![project.skills[i].clone(), project.skills[j].clone()];
-                        pair.sort();
-                        let key = pair.join(",");
-                        
-                        skill_combinations
-                            .entry(key)
-                            .or_insert_with(Vec::new)
-                            .push(project.id.clone());
-                    }
-                }
-                
-                if project.skills.len() >= 3 {
-                    let mut sorted_skills = project.skills.clone();
-                    sorted_skills.sort();
-                    let key = sorted_skills.join(",");
-                    skill_combinations
-                        .entry(key)
-                        .or_insert_with(Vec::new)
-                        .push(project.id.clone());
-                }
-            }
-        }
-        
-        let significant_clusters: HashMap<String, Vec<String>> = skill_combinations
-            .into_iter()
-            .filter(|(_, projects)| projects.len() > 1)
-            .collect();
-        
-        let result = MCPToolResult {
-            content: vec![MCPToolContent {
-                content_type: "text".to_string(),
-                text: serde_json::to_string_pretty(&significant_clusters)?,
-            }],
+        let skill_graph = build_skill_graph(&self.resume, &self.index);
+        let skill_graph_content = MCPResourceContent {
+            uri: "resume://skill_graph".to_string(),
+            mime_type: "application/json".to_string(),
+            text: serde_json::to_string_pretty(&skill_graph)?,
         };
-        
-        fs::write(
-            format!("{}/tools/find_skill_clusters.json", self.output_dir),
-            serde_json::to_string_pretty(&result)?,
+        write(
+            &format!("{}/resources/skill_graph.json", self.output_dir),
+            serde_json::to_string_pretty(&skill_graph_content)?,
         )?;
 
-        let basic_info_result = MCPToolResult {
-            content: vec![MCPToolContent {
-                content_type: "text".to_string(),
-                text: serde_json::to_string_pretty(&self.resume.info)?,
-            }],
+        let dot = skill_graph.to_dot();
+        let graphml = skill_graph.to_graphml();
+        write(&format!("{}/skill_graph.dot", self.output_dir), dot.clone())?;
+        write(&format!("{}/skill_graph.graphml", self.output_dir), graphml.clone())?;
+
+        create_dir_all(&format!("{}/resources/skill_graph", self.output_dir))?;
+        let dot_content = MCPResourceContent {
+            uri: "resume://skill_graph/dot".to_string(),
+            mime_type: "text/vnd.graphviz".to_string(),
+            text: dot,
+        };
+        write(
+            &format!("{}/resources/skill_graph/dot.json", self.output_dir),
+            serde_json::to_string_pretty(&dot_content)?,
+        )?;
+        let graphml_content = MCPResourceContent {
+            uri: "resume://skill_graph/graphml".to_string(),
+            mime_type: "application/graphml+xml".to_string(),
+            text: graphml,
         };
-        fs::write(
-            format!("{}/tools/get_basic_info.json", self.output_dir),
-            serde_json::to_string_pretty(&basic_info_result)?
+        write(
+            &format!("{}/resources/skill_graph/graphml.json", self.output_dir),
+            serde_json::to_string_pretty(&graphml_content)?,
         )?;
 
-        let indexes = serde_json::json!({
-            "skill_to_projects": &self.index.skill_to_projects,
-            "skill_to_experiences": &self.index.skill_to_experiences,
-            "project_to_experiences": &self.index.project_to_experiences,
-        });
-        let indexes_result = MCPToolResult {
-            content: vec![MCPToolContent {
-                content_type: "text".to_string(),
-                text: serde_json::to_string_pretty(&indexes)?,
-            }],
+        let match_artifacts = build_match_artifacts(&self.resume, &self.index);
+        create_dir_all(&format!("{}/resources/match", self.output_dir))?;
+        let vocabulary_content = MCPResourceContent {
+            uri: "resume://match/vocabulary".to_string(),
+            mime_type: "application/json".to_string(),
+            text: serde_json::to_string(&match_artifacts.vocabulary)?,
+        };
+        write(
+            &format!("{}/resources/match/vocabulary.json", self.output_dir),
+            serde_json::to_string_pretty(&vocabulary_content)?,
+        )?;
+        let vectors_content = MCPResourceContent {
+            uri: "resume://match/vectors".to_string(),
+            mime_type: "application/json".to_string(),
+            text: serde_json::to_string(&match_artifacts.vectors)?,
         };
-        fs::write(
-            format!("{}/tools/get_resume_indexes.json", self.output_dir),
-            serde_json::to_string_pretty(&indexes_result)?
+        write(
+            &format!("{}/resources/match/vectors.json", self.output_dir),
+            serde_json::to_string_pretty(&vectors_content)?,
         )?;
 
-        for experience in &self.resume.experiences {
-            let result = MCPToolResult {
-                content: vec![MCPToolContent {
-                    content_type: "text".to_string(),
-                    text: serde_json::to_string_pretty(&experience)?,
-                }],
+        let json_resume = serde_json::to_string_pretty(&JsonResume::from(&self.resume))?;
+        write(&format!("{}/resume.json", self.output_dir), json_resume.clone())?;
+
+        let json_resume_content = MCPResourceContent {
+            uri: "resume://jsonresume".to_string(),
+            mime_type: "application/json".to_string(),
+            text: json_resume,
+        };
+        write(
+            &format!("{}/resources/jsonresume.json", self.output_dir),
+            serde_json::to_string_pretty(&json_resume_content)?,
+        )?;
+
+        let markdown_ids = self.markdown_project_ids();
+        if !markdown_ids.is_empty() {
+            create_dir_all(&format!("{}/resources/markdown", self.output_dir))?;
+        }
+        for id in markdown_ids {
+            let markdown_content = MCPResourceContent {
+                uri: format!("resume://markdown/{}", id),
+                mime_type: "text/markdown".to_string(),
+                text: self.project_markdown[id].clone(),
             };
-            
-            fs::write(
-                format!("{}/tools/get_experience_details/{}.json", self.output_dir, experience.id),
-                serde_json::to_string_pretty(&result)?,
+            write(
+                &format!("{}/resources/markdown/{}.json", self.output_dir, id),
+                serde_json::to_string_pretty(&markdown_content)?,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn generate_entity_resources<'a, T: Serialize + 'a>(
+        &self,
+        kind: &str,
+        entities: impl Iterator<Item = (&'a str, &'a T)>,
+    ) -> Result<()> {
+        create_dir_all(&format!("{}/resources/{}", self.output_dir, kind))?;
+        for (id, entity) in entities {
+            let content = MCPResourceContent {
+                uri: format!("resume://{}/{}", kind, id),
+                mime_type: "application/json".to_string(),
+                text: serde_json::to_string_pretty(entity)?,
+            };
+            write(
+                &format!("{}/resources/{}/{}.json", self.output_dir, kind, id),
+                serde_json::to_string_pretty(&content)?,
             )?;
         }
+        Ok(())
+    }
 
-        for project in &self.resume.projects {
-            let result = MCPToolResult {
-                content: vec![MCPToolContent {
-                    content_type: "text".to_string(),
-                    text: serde_json::to_string_pretty(&project)?,
+    pub fn generate_tool_results(&self) -> Result<()> {
+        let context = self.tool_context();
+        for tool in self.tools.iter().filter(|tool| tool.precomputed()) {
+            let dir = format!("{}/tools/{}", self.output_dir, tool.name());
+            let combinations = tool.arguments(&context);
+            if combinations != [Vec::<String>::new()] {
+                create_dir_all(&dir)?;
+            }
+            for arguments in combinations {
+                let result = tool.call(&context, &arguments)?;
+                let path = match arguments.split_last() {
+                    None => format!("{}.json", dir),
+                    Some((last, [])) => format!("{}/{}.json", dir, last),
+                    Some((last, parents)) => {
+                        let parent = format!("{}/{}", dir, parents.join("/"));
+                        create_dir_all(&parent)?;
+                        format!("{}/{}.json", parent, last)
+                    }
+                };
+                write(&path, serde_json::to_string_pretty(&result)?)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn generate_prompts(&self) -> Result<()> {
+        let variables = resume_variables(&self.resume);
+        for prompt in &self.prompts {
+            let result = MCPPromptResult {
+                description: prompt.description.clone(),
+                messages: vec![MCPPromptMessage {
+                    role: "user".to_string(),
+                    content: MCPToolContent {
+                        content_type: "text".to_string(),
+                        text: render_prompt(&prompt.template, &variables),
+                    },
                 }],
             };
-            
-            fs::write(
-                format!("{}/tools/get_project_details/{}.json", self.output_dir, project.id),
+            write(
+                &format!("{}/prompts/{}.json", self.output_dir, prompt.name),
                 serde_json::to_string_pretty(&result)?,
             )?;
         }
@@ -554,28 +926,69 @@ impl StaticGenerator {
         Ok(())
     }
 
-    pub fn generate_indexes(&self) -> Result<(), Box<dyn std::error::Error>> {
-        fs::write(
-            format!("{}/indexes/skill_to_projects.json", self.output_dir),
+    pub fn generate_indexes(&self) -> Result<()> {
+        write(
+            &format!("{}/indexes/skill_to_projects.json", self.output_dir),
             serde_json::to_string_pretty(&self.index.skill_to_projects)?,
         )?;
-        fs::write(
-            format!("{}/indexes/skill_to_experiences.json", self.output_dir),
+        write(
+            &format!("{}/indexes/skill_to_experiences.json", self.output_dir),
             serde_json::to_string_pretty(&self.index.skill_to_experiences)?,
         )?;
-        fs::write(
-            format!("{}/indexes/project_to_experiences.json", self.output_dir),
+        write(
+            &format!("{}/indexes/project_to_experiences.json", self.output_dir),
             serde_json::to_string_pretty(&self.index.project_to_experiences)?,
         )?;
+        write(
+            &format!("{}/indexes/skill_to_education.json", self.output_dir),
+            serde_json::to_string_pretty(&self.index.skill_to_education)?,
+        )?;
+        write(
+            &format!("{}/indexes/skill_to_certifications.json", self.output_dir),
+            serde_json::to_string_pretty(&self.index.skill_to_certifications)?,
+        )?;
+        write(
+            &format!("{}/indexes/skill_to_publications.json", self.output_dir),
+            serde_json::to_string_pretty(&self.index.skill_to_publications)?,
+        )?;
 
         Ok(())
     }
 }
 
+fn create_dir_all(path: &str) -> Result<()> {
+    fs::create_dir_all(path).map_err(|source| Error::Io {
+        path: Path::new(path).to_path_buf(),
+        source,
+    })
+}
+
+fn write(path: &str, contents: String) -> Result<()> {
+    fs::write(path, contents).map_err(|source| Error::Io {
+        path: Path::new(path).to_path_buf(),
+        source,
+    })
+}
+
+pub fn slugify(value: &str) -> String {
+    let mut slug = String::new();
+    for c in value.trim().chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
 pub fn build_index(resume: &Resume) -> ResumeIndex {
     let mut skill_to_projects = HashMap::new();
     let mut skill_to_experiences = HashMap::new();
     let mut project_to_experiences = HashMap::new();
+    let mut skill_to_education: HashMap<String, Vec<String>> = HashMap::new();
+    let mut skill_to_certifications: HashMap<String, Vec<String>> = HashMap::new();
+    let mut skill_to_publications: HashMap<String, Vec<String>> = HashMap::new();
     
     let experience_lookup: HashMap<String, Experience> = 
         resume.experiences.iter().map(|e| (e.id.clone(), e.clone())).collect();
@@ -583,6 +996,12 @@ pub fn build_index(resume: &Resume) -> ResumeIndex {
         resume.projects.iter().map(|p| (p.id.clone(), p.clone())).collect();
     let skill_lookup: HashMap<String, Skill> = 
         resume.skills.iter().map(|s| (s.id.clone(), s.clone())).collect();
+    let education_lookup: HashMap<String, Education> =
+        resume.education.iter().map(|e| (e.id.clone(), e.clone())).collect();
+    let certification_lookup: HashMap<String, Certification> =
+        resume.certifications.iter().map(|c| (c.id.clone(), c.clone())).collect();
+    let publication_lookup: HashMap<String, Publication> =
+        resume.publications.iter().map(|p| (p.id.clone(), p.clone())).collect();
 
     for project in &resume.projects {
         for skill_id in &project.skills {
@@ -611,7 +1030,44 @@ pub fn build_index(resume: &Resume) -> ResumeIndex {
         }
     }
 
-    for vec in skill_to_experiences.values_mut() {
+    for education in &resume.education {
+        let project_skills = education
+            .projects
+            .iter()
+            .filter_map(|id| project_lookup.get(id))
+            .flat_map(|project| &project.skills);
+        for skill_id in education.skills.iter().chain(project_skills) {
+            skill_to_education
+                .entry(skill_id.clone())
+                .or_default()
+                .push(education.id.clone());
+        }
+    }
+
+    for certification in &resume.certifications {
+        for skill_id in &certification.skills {
+            skill_to_certifications
+                .entry(skill_id.clone())
+                .or_default()
+                .push(certification.id.clone());
+        }
+    }
+
+    for publication in &resume.publications {
+        for skill_id in &publication.skills {
+            skill_to_publications
+                .entry(skill_id.clone())
+                .or_default()
+                .push(publication.id.clone());
+        }
+    }
+
+    for vec in skill_to_experiences
+        .values_mut()
+        .chain(skill_to_education.values_mut())
+        .chain(skill_to_certifications.values_mut())
+        .chain(skill_to_publications.values_mut())
+    {
         vec.sort();
         vec.dedup();
     }
@@ -620,8 +1076,14 @@ pub fn build_index(resume: &Resume) -> ResumeIndex {
         skill_to_projects,
         skill_to_experiences,
         project_to_experiences,
+        skill_to_education,
+        skill_to_certifications,
+        skill_to_publications,
         experience_lookup,
         project_lookup,
         skill_lookup,
+        education_lookup,
+        certification_lookup,
+        publication_lookup,
     }
 }
\ No newline at end of file
@@ -0,0 +1,354 @@
+use crate::{
+    Certification, Config, Education, Error, Experience, GeneratorOptions, JsonResume, Project,
+    Publication, Result, Skill,
+    check_custom_tools, default_prompts,
+    jsonresume::is_json_resume,
+    markdown::{MARKDOWN_EXTENSIONS, is_markdown, parse_markdown_project},
+};
+use serde::{Deserialize, de::DeserializeOwned};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+const CONFIG_EXTENSIONS: [&str; 4] = ["json", "yaml", "yml", "toml"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Json,
+    Yaml,
+    Toml,
+}
+
+impl ConfigFormat {
+    pub fn from_path(path: &Path) -> Self {
+        match path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_ascii_lowercase())
+            .as_deref()
+        {
+            Some("yaml") | Some("yml") => ConfigFormat::Yaml,
+            Some("toml") => ConfigFormat::Toml,
+            _ => ConfigFormat::Json,
+        }
+    }
+
+    pub(crate) fn parse<T: DeserializeOwned>(self, path: &Path, content: &str) -> Result<T> {
+        match self {
+            ConfigFormat::Json => {
+                serde_json::from_str(content).map_err(|err| Error::json_parse(path, err))
+            }
+            ConfigFormat::Yaml => {
+                serde_yaml::from_str(content).map_err(|err| Error::yaml_parse(path, err))
+            }
+            ConfigFormat::Toml => {
+                toml::from_str(content).map_err(|err| Error::toml_parse(path, content, err))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ResumeFragment {
+    #[serde(default)]
+    pub experiences: Vec<Experience>,
+    #[serde(default)]
+    pub projects: Vec<Project>,
+    #[serde(default)]
+    pub skills: Vec<Skill>,
+    #[serde(default)]
+    pub education: Vec<Education>,
+    #[serde(default)]
+    pub certifications: Vec<Certification>,
+    #[serde(default)]
+    pub publications: Vec<Publication>,
+}
+
+impl ResumeFragment {
+    pub fn is_empty(&self) -> bool {
+        self.experiences.is_empty()
+            && self.projects.is_empty()
+            && self.skills.is_empty()
+            && self.education.is_empty()
+            && self.certifications.is_empty()
+            && self.publications.is_empty()
+    }
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|source| Error::ConfigRead {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn parse_file<T: DeserializeOwned>(path: &Path) -> Result<T> {
+    let content = read(path)?;
+    ConfigFormat::from_path(path).parse(path, &content)
+}
+
+fn expand(base_dir: &Path, config_path: &Path, pattern: &str) -> Result<Vec<PathBuf>> {
+    let full = base_dir.join(pattern);
+    let invalid = |message: String| Error::Include {
+        path: config_path.to_path_buf(),
+        pattern: pattern.to_string(),
+        message,
+    };
+    let full = full
+        .to_str()
+        .ok_or_else(|| invalid("path is not valid UTF-8".to_string()))?;
+    let mut paths = Vec::new();
+    for entry in glob::glob(full).map_err(|err| invalid(err.to_string()))? {
+        let path = entry.map_err(|err| invalid(err.to_string()))?;
+        if path.is_file() {
+            paths.push(path);
+        }
+    }
+    if paths.is_empty() && !pattern.contains(['*', '?', '[']) {
+        // A literal path that matches nothing is a typo, not an empty glob.
+        read(&base_dir.join(pattern))?;
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+// The file each entry was read from, so that errors can point at it.
+#[derive(Debug, Clone, Default)]
+pub struct Origins {
+    seen: HashMap<&'static str, HashMap<String, PathBuf>>,
+}
+
+impl Origins {
+    pub fn get(&self, kind: &str, id: &str) -> Option<&Path> {
+        self.seen.get(kind)?.get(id).map(PathBuf::as_path)
+    }
+
+    fn track(&mut self, kind: &'static str, id: &str, path: &Path) -> Result<()> {
+        let seen = self.seen.entry(kind).or_default();
+        match seen.get(id) {
+            // Duplicates within one file are reported by validation with their JSON path.
+            Some(first) if first != path => Err(Error::DuplicateId {
+                kind,
+                id: id.to_string(),
+                first: first.clone(),
+                second: path.to_path_buf(),
+            }),
+            Some(_) => Ok(()),
+            None => {
+                seen.insert(id.to_string(), path.to_path_buf());
+                Ok(())
+            }
+        }
+    }
+
+    fn track_ids<'a>(
+        &mut self,
+        kind: &'static str,
+        ids: impl IntoIterator<Item = &'a str>,
+        path: &Path,
+    ) -> Result<()> {
+        for id in ids {
+            self.track(kind, id, path)?;
+        }
+        Ok(())
+    }
+
+    fn track_fragment(&mut self, fragment: &ResumeFragment, path: &Path) -> Result<()> {
+        self.track_ids("experience", fragment.experiences.iter().map(|e| e.id.as_str()), path)?;
+        self.track_ids("project", fragment.projects.iter().map(|p| p.id.as_str()), path)?;
+        self.track_ids("skill", fragment.skills.iter().map(|s| s.id.as_str()), path)?;
+        self.track_ids("education", fragment.education.iter().map(|e| e.id.as_str()), path)?;
+        self.track_ids("certification", fragment.certifications.iter().map(|c| c.id.as_str()), path)?;
+        self.track_ids("publication", fragment.publications.iter().map(|p| p.id.as_str()), path)
+    }
+
+    fn track_config(&mut self, config: &Config, path: &Path) -> Result<()> {
+        let resume = &config.resume;
+        self.track_ids("experience", resume.experiences.iter().map(|e| e.id.as_str()), path)?;
+        self.track_ids("project", resume.projects.iter().map(|p| p.id.as_str()), path)?;
+        self.track_ids("skill", resume.skills.iter().map(|s| s.id.as_str()), path)?;
+        self.track_ids("education", resume.education.iter().map(|e| e.id.as_str()), path)?;
+        self.track_ids("certification", resume.certifications.iter().map(|c| c.id.as_str()), path)?;
+        self.track_ids("publication", resume.publications.iter().map(|p| p.id.as_str()), path)
+    }
+}
+
+fn load_markdown_project(config: &mut Config, path: &Path) -> Result<Project> {
+    let content = read(path)?;
+    let project = parse_markdown_project(path, &content)?;
+    config.project_markdown.insert(project.id.clone(), content);
+    Ok(project)
+}
+
+fn merge(config: &mut Config, fragment: ResumeFragment) {
+    config.resume.experiences.extend(fragment.experiences);
+    config.resume.projects.extend(fragment.projects);
+    config.resume.skills.extend(fragment.skills);
+    config.resume.education.extend(fragment.education);
+    config.resume.certifications.extend(fragment.certifications);
+    config.resume.publications.extend(fragment.publications);
+}
+
+fn resolve_includes(config: &mut Config, path: &Path, origins: &mut Origins) -> Result<()> {
+    let base_dir = path.parent().unwrap_or(Path::new("."));
+    let mut loaded = HashSet::new();
+    for pattern in config.include.clone() {
+        for include in expand(base_dir, path, &pattern)? {
+            // A file matched by more than one pattern is only merged the first time.
+            if !loaded.insert(fs::canonicalize(&include).unwrap_or_else(|_| include.clone())) {
+                continue;
+            }
+            if is_markdown(&include) {
+                let project = load_markdown_project(config, &include)?;
+                origins.track("project", &project.id, &include)?;
+                config.resume.projects.push(project);
+                continue;
+            }
+            let fragment: ResumeFragment = parse_file(&include)?;
+            // Usually a single entry written without its section, which would otherwise be dropped.
+            if fragment.is_empty() {
+                return Err(Error::Include {
+                    path: path.to_path_buf(),
+                    pattern,
+                    message: format!(
+                        "{} has no experiences, projects, skills, education, certifications or publications",
+                        include.display()
+                    ),
+                });
+            }
+            origins.track_fragment(&fragment, &include)?;
+            merge(config, fragment);
+        }
+    }
+    Ok(())
+}
+
+fn section_files(dir: &Path, section: &str, extensions: &[&str]) -> Result<Vec<PathBuf>> {
+    let section_dir = dir.join(section);
+    if !section_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let entries = fs::read_dir(&section_dir).map_err(|source| Error::ConfigRead {
+        path: section_dir.clone(),
+        source,
+    })?;
+    let mut paths = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|source| Error::ConfigRead {
+                path: section_dir.clone(),
+                source,
+            })?
+            .path();
+        let supported = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| extensions.contains(&ext.to_ascii_lowercase().as_str()));
+        if path.is_file() && supported {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+fn load_directory(dir: &Path) -> Result<Config> {
+    let base = CONFIG_EXTENSIONS
+        .iter()
+        .map(|ext| dir.join(format!("config.{}", ext)))
+        .find(|path| path.is_file())
+        .unwrap_or_else(|| dir.join("config.json"));
+    let mut config = load_config_file(&base)?;
+    let mut origins = Origins::default();
+    origins.track_config(&config, &base)?;
+    resolve_includes(&mut config, &base, &mut origins)?;
+
+    let mut fragment = ResumeFragment::default();
+    for path in section_files(dir, "experiences", &CONFIG_EXTENSIONS)? {
+        let experience: Experience = parse_file(&path)?;
+        origins.track("experience", &experience.id, &path)?;
+        fragment.experiences.push(experience);
+    }
+    let project_extensions = [CONFIG_EXTENSIONS.as_slice(), &MARKDOWN_EXTENSIONS].concat();
+    for path in section_files(dir, "projects", &project_extensions)? {
+        let project: Project = if is_markdown(&path) {
+            load_markdown_project(&mut config, &path)?
+        } else {
+            parse_file(&path)?
+        };
+        origins.track("project", &project.id, &path)?;
+        fragment.projects.push(project);
+    }
+    for path in section_files(dir, "skills", &CONFIG_EXTENSIONS)? {
+        let skill: Skill = parse_file(&path)?;
+        origins.track("skill", &skill.id, &path)?;
+        fragment.skills.push(skill);
+    }
+    for path in section_files(dir, "education", &CONFIG_EXTENSIONS)? {
+        let education: Education = parse_file(&path)?;
+        origins.track("education", &education.id, &path)?;
+        fragment.education.push(education);
+    }
+    for path in section_files(dir, "certifications", &CONFIG_EXTENSIONS)? {
+        let certification: Certification = parse_file(&path)?;
+        origins.track("certification", &certification.id, &path)?;
+        fragment.certifications.push(certification);
+    }
+    for path in section_files(dir, "publications", &CONFIG_EXTENSIONS)? {
+        let publication: Publication = parse_file(&path)?;
+        origins.track("publication", &publication.id, &path)?;
+        fragment.publications.push(publication);
+    }
+    merge(&mut config, fragment);
+    config.origins = origins;
+
+    Ok(config)
+}
+
+fn load_config_file(path: &Path) -> Result<Config> {
+    let format = ConfigFormat::from_path(path);
+    let content = read(path)?;
+    let value: serde_json::Value = format.parse(path, &content)?;
+    if is_json_resume(&value) {
+        return json_resume_from_str(path, format, &content);
+    }
+    format.parse(path, &content)
+}
+
+pub fn load_config(path: impl AsRef<Path>) -> Result<Config> {
+    let path = path.as_ref();
+    let config = if path.is_dir() {
+        load_directory(path)?
+    } else {
+        let mut config = load_config_file(path)?;
+        let mut origins = Origins::default();
+        origins.track_config(&config, path)?;
+        resolve_includes(&mut config, path, &mut origins)?;
+        config.origins = origins;
+        config
+    };
+    check_custom_tools(&config.custom_tools)?;
+    Ok(config)
+}
+
+pub fn load_json_resume(path: impl AsRef<Path>) -> Result<Config> {
+    let path = path.as_ref();
+    let content = read(path)?;
+    json_resume_from_str(path, ConfigFormat::from_path(path), &content)
+}
+
+fn json_resume_from_str(path: &Path, format: ConfigFormat, content: &str) -> Result<Config> {
+    let json_resume: JsonResume = format.parse(path, content)?;
+    Ok(Config {
+        resume: json_resume.into_resume()?,
+        include: Vec::new(),
+        options: GeneratorOptions::default(),
+        prompts: default_prompts(),
+        custom_tools: Vec::new(),
+        project_markdown: HashMap::new(),
+        origins: Origins::default(),
+    })
+}
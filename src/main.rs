@@ -1,19 +1,18 @@
-use resume_smg::{Config, StaticGenerator};
-use std::fs;
+use resume_smg::{StaticGenerator, load_config, load_json_resume};
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let args: Vec<String> = std::env::args().collect();
-    let binding = "config.json".to_string();
-    let config_path = args.get(1).unwrap_or(&binding);
-    let binding_static = "./dist".to_string();
-    let output_dir = args.get(2).unwrap_or(&binding_static);
+fn run(config_path: &str, output_dir: &str, json_resume: bool) -> resume_smg::Result<()> {
+    let config = if json_resume {
+        load_json_resume(config_path)?
+    } else {
+        load_config(config_path)?
+    };
 
-    let config_content = fs::read_to_string(config_path)
-        .expect("Failed to read config file");
-    let config: Config = serde_json::from_str(&config_content)
-        .expect("Failed to parse config file");
-
-    let generator = StaticGenerator::new(config.resume, output_dir.clone());
+    let generator = StaticGenerator::new(config.resume, output_dir.to_string())
+        .with_project_markdown(config.project_markdown)
+        .with_origins(config.origins)
+        .with_options(config.options)
+        .with_prompts(config.prompts)
+        .with_custom_tools(config.custom_tools);
     generator.generate()?;
 
     println!("\n🎉 Static MCP site generated successfully!");
@@ -22,3 +21,21 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+fn main() {
+    let mut json_resume = false;
+    let mut args = Vec::new();
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--json-resume" => json_resume = true,
+            _ => args.push(arg),
+        }
+    }
+    let config_path = args.first().map(String::as_str).unwrap_or("config.json");
+    let output_dir = args.get(1).map(String::as_str).unwrap_or("./dist");
+
+    if let Err(err) = run(config_path, output_dir, json_resume) {
+        eprintln!("error: {}", err);
+        std::process::exit(err.exit_code());
+    }
+}
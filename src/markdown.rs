@@ -0,0 +1,84 @@
+use crate::{ConfigFormat, Error, Project, Result};
+use serde::Deserialize;
+use std::path::Path;
+
+pub const MARKDOWN_EXTENSIONS: [&str; 2] = ["md", "markdown"];
+
+#[derive(Debug, Deserialize)]
+struct ProjectFrontMatter {
+    id: Option<String>,
+    title: String,
+    #[serde(default)]
+    duration: Option<String>,
+    #[serde(default)]
+    skills: Vec<String>,
+    #[serde(default)]
+    aliases: Vec<String>,
+}
+
+pub fn is_markdown(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| MARKDOWN_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()))
+}
+
+fn split_front_matter<'a>(path: &Path, content: &'a str) -> Result<(ConfigFormat, &'a str, &'a str)> {
+    let missing = |message: &str| Error::Parse {
+        path: path.to_path_buf(),
+        line: 1,
+        column: 1,
+        message: message.to_string(),
+    };
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+    let (format, delimiter) = match content.lines().next().map(str::trim_end) {
+        Some("---") => (ConfigFormat::Yaml, "---"),
+        Some("+++") => (ConfigFormat::Toml, "+++"),
+        _ => return Err(missing("expected front matter starting with '---' or '+++'")),
+    };
+    let after_open = &content[content.find('\n').map(|i| i + 1).unwrap_or(content.len())..];
+    let mut offset = 0;
+    for line in after_open.split_inclusive('\n') {
+        if line.trim_end() == delimiter {
+            let front = &after_open[..offset];
+            let body = &after_open[offset + line.len()..];
+            return Ok((format, front, body));
+        }
+        offset += line.len();
+    }
+    Err(missing("front matter is not closed"))
+}
+
+pub fn parse_markdown_project(path: &Path, content: &str) -> Result<Project> {
+    let (format, front, body) = split_front_matter(path, content)?;
+    let front_matter: ProjectFrontMatter = format.parse(path, front).map_err(|err| match err {
+        // Account for the opening delimiter line.
+        Error::Parse {
+            path,
+            line,
+            column,
+            message,
+        } => Error::Parse {
+            path,
+            line: line + 1,
+            column,
+            message,
+        },
+        other => other,
+    })?;
+    let id = match front_matter.id {
+        Some(id) => id,
+        None => path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default()
+            .to_string(),
+    };
+    Ok(Project {
+        id,
+        title: front_matter.title,
+        duration: front_matter.duration,
+        description: body.trim().to_string(),
+        skills: front_matter.skills,
+        aliases: front_matter.aliases,
+    })
+}
@@ -0,0 +1,200 @@
+use crate::{
+    Resume, ResumeIndex,
+    jsonresume::mentions,
+    similarity::{TermVector, TfIdf, cosine, round},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+const TEXT_WEIGHT: f64 = 0.5;
+const SKILL_WEIGHT: f64 = 0.5;
+const TOP_MATCHES: usize = 3;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Vocabulary {
+    pub documents: usize,
+    pub idf: BTreeMap<String, f64>,
+    pub skills: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EntityVector {
+    pub terms: TermVector,
+    pub skills: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TermVectors {
+    pub projects: BTreeMap<String, EntityVector>,
+    pub experiences: BTreeMap<String, EntityVector>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MatchArtifacts {
+    pub vocabulary: Vocabulary,
+    pub vectors: TermVectors,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EntityMatch {
+    pub id: String,
+    pub score: f64,
+    pub text_similarity: f64,
+    pub matched_skills: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobMatch {
+    pub score: f64,
+    pub matched_skills: Vec<String>,
+    pub projects: Vec<EntityMatch>,
+    pub experiences: Vec<EntityMatch>,
+}
+
+fn compact(vector: TermVector) -> TermVector {
+    vector
+        .into_iter()
+        .map(|(term, weight)| (term, (weight * 10000.0).round() / 10000.0))
+        .filter(|(_, weight)| *weight > 0.0)
+        .collect()
+}
+
+pub fn build_match_artifacts(resume: &Resume, index: &ResumeIndex) -> MatchArtifacts {
+    let project_texts: BTreeMap<&str, String> = resume
+        .projects
+        .iter()
+        .map(|p| (p.id.as_str(), format!("{}\n{}", p.title, p.description)))
+        .collect();
+    let experience_texts: BTreeMap<&str, String> = resume
+        .experiences
+        .iter()
+        .map(|e| {
+            let mut text = format!("{}\n{}", e.title, e.employer);
+            for project in e.projects.iter().filter_map(|id| project_texts.get(id.as_str())) {
+                text.push('\n');
+                text.push_str(project);
+            }
+            (e.id.as_str(), text)
+        })
+        .collect();
+
+    let tfidf = TfIdf::fit(
+        project_texts
+            .values()
+            .chain(experience_texts.values())
+            .map(String::as_str),
+    );
+
+    let mut skills = BTreeMap::new();
+    for skill in &resume.skills {
+        let names = [&skill.id, &skill.name].into_iter().chain(&skill.aliases);
+        for name in names {
+            skills.insert(name.trim().to_lowercase(), skill.id.clone());
+        }
+    }
+
+    let projects = resume
+        .projects
+        .iter()
+        .map(|p| {
+            let vector = EntityVector {
+                terms: compact(tfidf.vector(&project_texts[p.id.as_str()])),
+                skills: p.skills.clone(),
+            };
+            (p.id.clone(), vector)
+        })
+        .collect();
+    let experiences = resume
+        .experiences
+        .iter()
+        .map(|e| {
+            let mut skills: BTreeSet<&String> = BTreeSet::new();
+            for project in e.projects.iter().filter_map(|id| index.project_lookup.get(id)) {
+                skills.extend(&project.skills);
+            }
+            let vector = EntityVector {
+                terms: compact(tfidf.vector(&experience_texts[e.id.as_str()])),
+                skills: skills.into_iter().cloned().collect(),
+            };
+            (e.id.clone(), vector)
+        })
+        .collect();
+
+    MatchArtifacts {
+        vocabulary: Vocabulary {
+            documents: tfidf.documents,
+            idf: tfidf.idf.into_iter().map(|(term, idf)| (term, round(idf))).collect(),
+            skills,
+        },
+        vectors: TermVectors { projects, experiences },
+    }
+}
+
+fn rank(
+    entities: &BTreeMap<String, EntityVector>,
+    job: &TermVector,
+    job_skills: &BTreeSet<&str>,
+) -> Vec<EntityMatch> {
+    let mut matches: Vec<EntityMatch> = entities
+        .iter()
+        .map(|(id, entity)| {
+            let text_similarity = cosine(job, &entity.terms);
+            let matched_skills: Vec<String> = entity
+                .skills
+                .iter()
+                .filter(|skill| job_skills.contains(skill.as_str()))
+                .cloned()
+                .collect();
+            let skill_coverage = if job_skills.is_empty() {
+                0.0
+            } else {
+                matched_skills.len() as f64 / job_skills.len() as f64
+            };
+            EntityMatch {
+                id: id.clone(),
+                score: round(TEXT_WEIGHT * text_similarity + SKILL_WEIGHT * skill_coverage),
+                text_similarity: round(text_similarity),
+                matched_skills,
+            }
+        })
+        .filter(|m| m.score > 0.0)
+        .collect();
+    matches.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.id.cmp(&b.id)));
+    matches
+}
+
+pub fn match_job_description(artifacts: &MatchArtifacts, job_description: &str) -> JobMatch {
+    let text = job_description.to_lowercase();
+    let job_skills: BTreeSet<&str> = artifacts
+        .vocabulary
+        .skills
+        .iter()
+        .filter(|(phrase, _)| mentions(&text, phrase))
+        .map(|(_, id)| id.as_str())
+        .collect();
+
+    let tfidf = TfIdf {
+        documents: artifacts.vocabulary.documents,
+        idf: artifacts.vocabulary.idf.clone(),
+    };
+    let job = tfidf.vector(job_description);
+
+    let projects = rank(&artifacts.vectors.projects, &job, &job_skills);
+    let experiences = rank(&artifacts.vectors.experiences, &job, &job_skills);
+
+    let mut best: Vec<f64> = projects.iter().chain(&experiences).map(|m| m.score).collect();
+    best.sort_by(|a, b| b.total_cmp(a));
+    best.truncate(TOP_MATCHES);
+    let score = if best.is_empty() {
+        0.0
+    } else {
+        best.iter().sum::<f64>() / best.len() as f64
+    };
+
+    JobMatch {
+        score: round(score),
+        matched_skills: job_skills.into_iter().map(str::to_string).collect(),
+        projects,
+        experiences,
+    }
+}
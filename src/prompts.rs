@@ -0,0 +1,228 @@
+use crate::{Error, Resume, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+pub const RESUME_VARIABLES: [&str; 8] = [
+    "name",
+    "location",
+    "email",
+    "experiences",
+    "projects",
+    "skills",
+    "education",
+    "certifications",
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PromptArgument {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub required: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PromptTemplate {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub description: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub arguments: Vec<PromptArgument>,
+    pub template: String,
+}
+
+fn argument(name: &str, description: &str) -> PromptArgument {
+    PromptArgument {
+        name: name.to_string(),
+        description: Some(description.to_string()),
+        required: true,
+    }
+}
+
+pub fn default_prompts() -> Vec<PromptTemplate> {
+    vec![
+        PromptTemplate {
+            name: "summarize_candidate_for_role".to_string(),
+            title: Some("Summarize candidate for role".to_string()),
+            description: "Summarize how well the candidate fits a role".to_string(),
+            arguments: vec![argument("role", "Title of the role, e.g. 'Staff Backend Engineer'")],
+            template: concat!(
+                "Summarize in a few paragraphs how well {{name}} fits the role of {{role}}. ",
+                "Highlight the most relevant experience and skills, and point out anything missing.\n\n",
+                "Experience:\n{{experiences}}\n\n",
+                "Projects:\n{{projects}}\n\n",
+                "Skills: {{skills}}"
+            )
+            .to_string(),
+        },
+        PromptTemplate {
+            name: "write_cover_letter".to_string(),
+            title: Some("Write cover letter for job".to_string()),
+            description: "Write a cover letter for a job, based on the resume".to_string(),
+            arguments: vec![argument("job_description", "Full text of the job description")],
+            template: concat!(
+                "Write a one-page cover letter from {{name}} ({{email}}) for the job below. ",
+                "Only mention experience, projects and skills listed in the resume.\n\n",
+                "Job description:\n{{job_description}}\n\n",
+                "Experience:\n{{experiences}}\n\n",
+                "Projects:\n{{projects}}\n\n",
+                "Skills: {{skills}}"
+            )
+            .to_string(),
+        },
+        PromptTemplate {
+            name: "compare_with_job_description".to_string(),
+            title: Some("Compare with job description".to_string()),
+            description: "Compare the resume with a job description, requirement by requirement".to_string(),
+            arguments: vec![argument("job_description", "Full text of the job description")],
+            template: concat!(
+                "For each requirement of the job description below, say whether {{name}}'s resume ",
+                "meets it and cite the experience, project, skill or certification that shows it.\n\n",
+                "Job description:\n{{job_description}}\n\n",
+                "Experience:\n{{experiences}}\n\n",
+                "Projects:\n{{projects}}\n\n",
+                "Skills: {{skills}}\n\n",
+                "Education:\n{{education}}\n\n",
+                "Certifications:\n{{certifications}}"
+            )
+            .to_string(),
+        },
+    ]
+}
+
+fn bullets(lines: impl Iterator<Item = String>) -> String {
+    let lines: Vec<String> = lines.map(|line| format!("- {}", line)).collect();
+    if lines.is_empty() {
+        "(none)".to_string()
+    } else {
+        lines.join("\n")
+    }
+}
+
+pub fn resume_variables(resume: &Resume) -> BTreeMap<&'static str, String> {
+    let month = "%Y-%m";
+    let experiences = bullets(resume.experiences.iter().map(|e| {
+        let end = e
+            .end_date
+            .map(|date| date.format(month).to_string())
+            .unwrap_or_else(|| "present".to_string());
+        format!("{} at {} ({} to {})", e.title, e.employer, e.start_date.format(month), end)
+    }));
+    let projects = bullets(
+        resume
+            .projects
+            .iter()
+            .map(|p| format!("{}: {}", p.title, p.description.trim())),
+    );
+    let skills = resume
+        .skills
+        .iter()
+        .map(|s| match s.proficiency {
+            Some(proficiency) => format!("{} ({})", s.name, proficiency.as_str()),
+            None => s.name.clone(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let education = bullets(resume.education.iter().map(|e| match &e.field {
+        Some(field) => format!("{} in {}, {}", e.degree, field, e.institution),
+        None => format!("{}, {}", e.degree, e.institution),
+    }));
+    let certifications = bullets(
+        resume
+            .certifications
+            .iter()
+            .map(|c| format!("{} ({})", c.name, c.issuer)),
+    );
+
+    BTreeMap::from([
+        ("name", resume.info.name.clone()),
+        ("location", resume.info.location.clone()),
+        ("email", resume.info.email.clone()),
+        ("experiences", experiences),
+        ("projects", projects),
+        ("skills", skills),
+        ("education", education),
+        ("certifications", certifications),
+    ])
+}
+
+fn prompt_error(prompt: &PromptTemplate, message: String) -> Error {
+    Error::Prompt {
+        name: prompt.name.clone(),
+        message,
+    }
+}
+
+pub fn check_prompts(prompts: &[PromptTemplate]) -> Result<()> {
+    let mut names = BTreeSet::new();
+    for prompt in prompts {
+        if !is_valid_name(&prompt.name) {
+            return Err(prompt_error(
+                prompt,
+                "names may only contain letters, digits, '_' and '-'".to_string(),
+            ));
+        }
+        if !names.insert(prompt.name.as_str()) {
+            return Err(prompt_error(prompt, "defined more than once".to_string()));
+        }
+        for argument in &prompt.arguments {
+            if RESUME_VARIABLES.contains(&argument.name.as_str()) {
+                return Err(prompt_error(
+                    prompt,
+                    format!("argument '{}' shadows a resume variable", argument.name),
+                ));
+            }
+        }
+        for placeholder in placeholders(&prompt.template) {
+            let known = RESUME_VARIABLES.contains(&placeholder)
+                || prompt.arguments.iter().any(|a| a.name == placeholder);
+            if !known {
+                return Err(prompt_error(
+                    prompt,
+                    format!("unknown placeholder '{{{{{}}}}}'", placeholder),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+// Names of prompts and tools become file names, so keep them to a safe character set.
+pub(crate) fn is_valid_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+pub(crate) fn placeholders(template: &str) -> Vec<&str> {
+    let mut found = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        found.push(rest[start + 2..start + 2 + end].trim());
+        rest = &rest[start + 2 + end + 2..];
+    }
+    found
+}
+
+// Resume variables are filled in now; argument placeholders are left for the bridge to substitute.
+pub fn render_prompt(template: &str, variables: &BTreeMap<&str, String>) -> String {
+    let mut rendered = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        let placeholder = &rest[start..start + 2 + end + 2];
+        rendered.push_str(&rest[..start]);
+        match variables.get(rest[start + 2..start + 2 + end].trim()) {
+            Some(value) => rendered.push_str(value),
+            None => rendered.push_str(placeholder),
+        }
+        rest = &rest[start + 2 + end + 2..];
+    }
+    rendered.push_str(rest);
+    rendered
+}
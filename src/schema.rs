@@ -0,0 +1,428 @@
+use crate::{
+    CareerGap, CareerGaps, CareerOverlap, Certification, Education, EntityMatch, Experience, JobMatch,
+    PersonalInfo, Project, Publication, PublicationKind, RelatedProject, RelatedSkill, SearchHit,
+    SearchResults, Skill, SkillCluster, SkillExperienceSummary, TimelineEntry,
+};
+use serde::Serialize;
+use serde_json::{Value, json};
+
+pub trait JsonSchema {
+    fn schema() -> Value;
+}
+
+impl<T: JsonSchema> JsonSchema for Vec<T> {
+    fn schema() -> Value {
+        array(T::schema())
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for &T {
+    fn schema() -> Value {
+        T::schema()
+    }
+}
+
+fn object(properties: Value, required: &[&str]) -> Value {
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    })
+}
+
+fn array(items: Value) -> Value {
+    json!({"type": "array", "items": items})
+}
+
+fn string() -> Value {
+    json!({"type": "string"})
+}
+
+fn nullable_string() -> Value {
+    json!({"type": ["string", "null"]})
+}
+
+fn strings() -> Value {
+    array(string())
+}
+
+fn date_time() -> Value {
+    json!({"type": "string", "format": "date-time"})
+}
+
+fn nullable_date_time() -> Value {
+    json!({"type": ["string", "null"], "format": "date-time"})
+}
+
+fn integer() -> Value {
+    json!({"type": "integer"})
+}
+
+fn number() -> Value {
+    json!({"type": "number"})
+}
+
+fn string_map(values: Value) -> Value {
+    json!({"type": "object", "additionalProperties": values})
+}
+
+// MCP requires structured tool output to be an object, so anything else is wrapped
+// in a `result` property, both here and in `MCPToolResult::new`.
+pub fn output_schema<T: JsonSchema>() -> Value {
+    wrap_output_schema(T::schema())
+}
+
+pub(crate) fn wrap_output_schema(schema: Value) -> Value {
+    if schema["type"] == "object" {
+        schema
+    } else {
+        object(json!({"result": schema}), &["result"])
+    }
+}
+
+pub(crate) fn structured_content<T: Serialize>(value: &T) -> serde_json::Result<Value> {
+    let value = serde_json::to_value(value)?;
+    if value.is_object() {
+        Ok(value)
+    } else {
+        Ok(json!({"result": value}))
+    }
+}
+
+pub(crate) fn resume_indexes_schema() -> Value {
+    let index = string_map(strings());
+    object(
+        json!({
+            "skill_to_projects": index,
+            "skill_to_experiences": index,
+            "project_to_experiences": index,
+            "skill_to_education": index,
+            "skill_to_certifications": index,
+            "skill_to_publications": index,
+        }),
+        &[
+            "skill_to_projects",
+            "skill_to_experiences",
+            "project_to_experiences",
+            "skill_to_education",
+            "skill_to_certifications",
+            "skill_to_publications",
+        ],
+    )
+}
+
+impl JsonSchema for PersonalInfo {
+    fn schema() -> Value {
+        object(
+            json!({
+                "name": string(),
+                "location": string(),
+                "phone_number": string(),
+                "email": string(),
+                "links": string_map(string()),
+            }),
+            &["name", "location", "phone_number", "email", "links"],
+        )
+    }
+}
+
+impl JsonSchema for Experience {
+    fn schema() -> Value {
+        object(
+            json!({
+                "id": string(),
+                "title": string(),
+                "employer": string(),
+                "start_date": date_time(),
+                "end_date": nullable_date_time(),
+                "projects": strings(),
+            }),
+            &["id", "title", "employer", "start_date", "end_date", "projects"],
+        )
+    }
+}
+
+impl JsonSchema for Project {
+    fn schema() -> Value {
+        object(
+            json!({
+                "id": string(),
+                "title": string(),
+                "duration": nullable_string(),
+                "description": string(),
+                "skills": strings(),
+                "aliases": strings(),
+            }),
+            &["id", "title", "duration", "description", "skills"],
+        )
+    }
+}
+
+fn skill_properties() -> Value {
+    json!({
+        "id": string(),
+        "name": string(),
+        "type": string(),
+        "category": string(),
+        "proficiency": {"type": "string", "enum": ["beginner", "intermediate", "advanced", "expert"]},
+        "aliases": strings(),
+    })
+}
+
+const SKILL_REQUIRED: [&str; 4] = ["id", "name", "type", "category"];
+
+impl JsonSchema for Skill {
+    fn schema() -> Value {
+        object(skill_properties(), &SKILL_REQUIRED)
+    }
+}
+
+impl JsonSchema for SkillExperienceSummary {
+    fn schema() -> Value {
+        let mut properties = skill_properties();
+        properties["years_of_experience"] = number();
+        properties["last_used"] = json!({
+            "type": ["string", "null"],
+            "description": "RFC 3339 date of the last use, or \"present\"",
+        });
+        properties["experiences"] = strings();
+        let required = [SKILL_REQUIRED.as_slice(), &["years_of_experience", "last_used", "experiences"]].concat();
+        object(properties, &required)
+    }
+}
+
+impl JsonSchema for Education {
+    fn schema() -> Value {
+        object(
+            json!({
+                "id": string(),
+                "institution": string(),
+                "degree": string(),
+                "field": nullable_string(),
+                "start_date": nullable_date_time(),
+                "end_date": nullable_date_time(),
+                "grade": nullable_string(),
+                "projects": strings(),
+                "skills": strings(),
+            }),
+            &["id", "institution", "degree", "projects", "skills"],
+        )
+    }
+}
+
+impl JsonSchema for Certification {
+    fn schema() -> Value {
+        object(
+            json!({
+                "id": string(),
+                "name": string(),
+                "issuer": string(),
+                "issued_date": date_time(),
+                "expiry_date": nullable_date_time(),
+                "credential_id": nullable_string(),
+                "url": nullable_string(),
+                "skills": strings(),
+            }),
+            &["id", "name", "issuer", "issued_date", "skills"],
+        )
+    }
+}
+
+impl JsonSchema for Publication {
+    fn schema() -> Value {
+        let kinds: Vec<&str> = PublicationKind::ALL.iter().map(PublicationKind::as_str).collect();
+        object(
+            json!({
+                "id": string(),
+                "title": string(),
+                "kind": {"type": "string", "enum": kinds},
+                "venue": nullable_string(),
+                "date": date_time(),
+                "co_authors": strings(),
+                "url": nullable_string(),
+                "skills": strings(),
+            }),
+            &["id", "title", "kind", "date", "co_authors", "skills"],
+        )
+    }
+}
+
+impl JsonSchema for RelatedSkill {
+    fn schema() -> Value {
+        object(
+            json!({
+                "id": string(),
+                "name": string(),
+                "weight": integer(),
+                "projects": integer(),
+                "experiences": integer(),
+            }),
+            &["id", "name", "weight", "projects", "experiences"],
+        )
+    }
+}
+
+impl JsonSchema for RelatedProject {
+    fn schema() -> Value {
+        object(
+            json!({
+                "id": string(),
+                "title": string(),
+                "score": number(),
+                "skill_similarity": number(),
+                "description_similarity": number(),
+                "shared_skills": strings(),
+                "employers": strings(),
+            }),
+            &[
+                "id",
+                "title",
+                "score",
+                "skill_similarity",
+                "description_similarity",
+                "shared_skills",
+                "employers",
+            ],
+        )
+    }
+}
+
+impl JsonSchema for SkillCluster {
+    fn schema() -> Value {
+        object(
+            json!({
+                "skills": strings(),
+                "support": integer(),
+                "projects": strings(),
+            }),
+            &["skills", "support", "projects"],
+        )
+    }
+}
+
+impl JsonSchema for TimelineEntry {
+    fn schema() -> Value {
+        json!({
+            "oneOf": [
+                object(
+                    json!({
+                        "type": {"const": "experience"},
+                        "id": string(),
+                        "title": string(),
+                        "employer": string(),
+                        "start_date": date_time(),
+                        "end_date": nullable_date_time(),
+                    }),
+                    &["type", "id", "title", "employer", "start_date", "end_date"],
+                ),
+                object(
+                    json!({
+                        "type": {"const": "gap"},
+                        "start_date": date_time(),
+                        "end_date": date_time(),
+                        "days": integer(),
+                    }),
+                    &["type", "start_date", "end_date", "days"],
+                ),
+            ]
+        })
+    }
+}
+
+impl JsonSchema for CareerGap {
+    fn schema() -> Value {
+        object(
+            json!({
+                "after": string(),
+                "before": string(),
+                "start_date": date_time(),
+                "end_date": date_time(),
+                "days": integer(),
+            }),
+            &["after", "before", "start_date", "end_date", "days"],
+        )
+    }
+}
+
+impl JsonSchema for CareerOverlap {
+    fn schema() -> Value {
+        object(
+            json!({
+                "experiences": {"type": "array", "items": string(), "minItems": 2, "maxItems": 2},
+                "start_date": date_time(),
+                "end_date": date_time(),
+                "days": integer(),
+            }),
+            &["experiences", "start_date", "end_date", "days"],
+        )
+    }
+}
+
+impl JsonSchema for CareerGaps {
+    fn schema() -> Value {
+        object(
+            json!({
+                "threshold_days": integer(),
+                "gaps": Vec::<CareerGap>::schema(),
+                "overlaps": Vec::<CareerOverlap>::schema(),
+            }),
+            &["threshold_days", "gaps", "overlaps"],
+        )
+    }
+}
+
+impl JsonSchema for SearchHit {
+    fn schema() -> Value {
+        object(
+            json!({
+                "id": string(),
+                "title": string(),
+                "score": integer(),
+                "fields": strings(),
+            }),
+            &["id", "title", "score", "fields"],
+        )
+    }
+}
+
+impl JsonSchema for SearchResults {
+    fn schema() -> Value {
+        object(
+            json!({
+                "term": string(),
+                "projects": Vec::<SearchHit>::schema(),
+                "experiences": Vec::<SearchHit>::schema(),
+                "skills": Vec::<SearchHit>::schema(),
+            }),
+            &["term", "projects", "experiences", "skills"],
+        )
+    }
+}
+
+impl JsonSchema for EntityMatch {
+    fn schema() -> Value {
+        object(
+            json!({
+                "id": string(),
+                "score": number(),
+                "text_similarity": number(),
+                "matched_skills": strings(),
+            }),
+            &["id", "score", "text_similarity", "matched_skills"],
+        )
+    }
+}
+
+impl JsonSchema for JobMatch {
+    fn schema() -> Value {
+        object(
+            json!({
+                "score": number(),
+                "matched_skills": strings(),
+                "projects": Vec::<EntityMatch>::schema(),
+                "experiences": Vec::<EntityMatch>::schema(),
+            }),
+            &["score", "matched_skills", "projects", "experiences"],
+        )
+    }
+}
@@ -0,0 +1,148 @@
+use crate::Resume;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+const TITLE_WEIGHT: u32 = 3;
+const EMPLOYER_WEIGHT: u32 = 2;
+const DESCRIPTION_WEIGHT: u32 = 1;
+
+const STOP_WORDS: [&str; 24] = [
+    "a", "an", "and", "are", "as", "at", "be", "by", "for", "from", "in", "into", "is", "it",
+    "of", "on", "or", "that", "the", "to", "was", "were", "with", "within",
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchHit {
+    pub id: String,
+    pub title: String,
+    pub score: u32,
+    pub fields: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SearchResults {
+    pub term: String,
+    pub projects: Vec<SearchHit>,
+    pub experiences: Vec<SearchHit>,
+    pub skills: Vec<SearchHit>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SearchIndex {
+    pub terms: BTreeMap<String, SearchResults>,
+}
+
+impl SearchIndex {
+    pub fn search(&self, query: &str) -> Option<&SearchResults> {
+        let term = tokenize(query).into_iter().next()?;
+        self.terms.get(&term)
+    }
+}
+
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(str::to_lowercase)
+        .filter(|token| token.chars().count() > 1 && !STOP_WORDS.contains(&token.as_str()))
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Entity {
+    Project,
+    Experience,
+    Skill,
+}
+
+#[derive(Default)]
+struct Document {
+    score: u32,
+    fields: Vec<String>,
+}
+
+type Postings = HashMap<String, HashMap<(Entity, String), Document>>;
+
+fn add_field(postings: &mut Postings, entity: Entity, id: &str, field: &str, text: &str, weight: u32) {
+    for token in tokenize(text) {
+        let document = postings
+            .entry(token)
+            .or_default()
+            .entry((entity, id.to_string()))
+            .or_default();
+        document.score += weight;
+        if !document.fields.iter().any(|f| f == field) {
+            document.fields.push(field.to_string());
+        }
+    }
+}
+
+pub fn build_search_index(resume: &Resume) -> SearchIndex {
+    let mut postings = Postings::new();
+    let mut titles: HashMap<(Entity, &str), &str> = HashMap::new();
+
+    for project in &resume.projects {
+        titles.insert((Entity::Project, &project.id), &project.title);
+        add_field(&mut postings, Entity::Project, &project.id, "title", &project.title, TITLE_WEIGHT);
+        add_field(
+            &mut postings,
+            Entity::Project,
+            &project.id,
+            "description",
+            &project.description,
+            DESCRIPTION_WEIGHT,
+        );
+    }
+
+    for experience in &resume.experiences {
+        titles.insert((Entity::Experience, &experience.id), &experience.title);
+        add_field(
+            &mut postings,
+            Entity::Experience,
+            &experience.id,
+            "title",
+            &experience.title,
+            TITLE_WEIGHT,
+        );
+        add_field(
+            &mut postings,
+            Entity::Experience,
+            &experience.id,
+            "employer",
+            &experience.employer,
+            EMPLOYER_WEIGHT,
+        );
+    }
+
+    for skill in &resume.skills {
+        titles.insert((Entity::Skill, &skill.id), &skill.name);
+        add_field(&mut postings, Entity::Skill, &skill.id, "name", &skill.name, TITLE_WEIGHT);
+    }
+
+    let terms = postings
+        .into_iter()
+        .map(|(term, documents)| {
+            let mut results = SearchResults {
+                term: term.clone(),
+                ..SearchResults::default()
+            };
+            for ((entity, id), document) in documents {
+                let hit = SearchHit {
+                    title: titles.get(&(entity, id.as_str())).copied().unwrap_or_default().to_string(),
+                    id,
+                    score: document.score,
+                    fields: document.fields,
+                };
+                match entity {
+                    Entity::Project => results.projects.push(hit),
+                    Entity::Experience => results.experiences.push(hit),
+                    Entity::Skill => results.skills.push(hit),
+                }
+            }
+            for hits in [&mut results.projects, &mut results.experiences, &mut results.skills] {
+                hits.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.id.cmp(&b.id)));
+            }
+            (term, results)
+        })
+        .collect();
+
+    SearchIndex { terms }
+}
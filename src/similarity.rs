@@ -0,0 +1,144 @@
+use crate::{Resume, ResumeIndex, search::tokenize};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+const SKILL_WEIGHT: f64 = 0.5;
+const DESCRIPTION_WEIGHT: f64 = 0.5;
+
+pub type TermVector = BTreeMap<String, f64>;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TfIdf {
+    pub documents: usize,
+    pub idf: BTreeMap<String, f64>,
+}
+
+impl TfIdf {
+    pub fn fit<'a>(documents: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut document_frequency: BTreeMap<String, usize> = BTreeMap::new();
+        let mut count = 0;
+        for document in documents {
+            count += 1;
+            let terms: BTreeSet<String> = tokenize(document).into_iter().collect();
+            for term in terms {
+                *document_frequency.entry(term).or_default() += 1;
+            }
+        }
+        // Smoothed so that a term present in every document still carries some weight.
+        let idf = document_frequency
+            .into_iter()
+            .map(|(term, df)| (term, ((1 + count) as f64 / (1 + df) as f64).ln() + 1.0))
+            .collect();
+        TfIdf { documents: count, idf }
+    }
+
+    pub fn vector(&self, text: &str) -> TermVector {
+        let terms = tokenize(text);
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        for term in &terms {
+            *counts.entry(term.clone()).or_default() += 1;
+        }
+        let mut vector: TermVector = counts
+            .into_iter()
+            .filter_map(|(term, count)| {
+                let idf = self.idf.get(&term)?;
+                Some((term, count as f64 / terms.len() as f64 * idf))
+            })
+            .collect();
+        let norm = vector.values().map(|w| w * w).sum::<f64>().sqrt();
+        if norm > 0.0 {
+            vector.values_mut().for_each(|w| *w /= norm);
+        }
+        vector
+    }
+}
+
+pub fn cosine(a: &TermVector, b: &TermVector) -> f64 {
+    a.iter()
+        .filter_map(|(term, weight)| b.get(term).map(|other| weight * other))
+        .sum()
+}
+
+pub fn jaccard<T: Ord>(a: &BTreeSet<T>, b: &BTreeSet<T>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
+
+pub(crate) fn round(value: f64) -> f64 {
+    (value * 1000.0).round() / 1000.0
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelatedProject {
+    pub id: String,
+    pub title: String,
+    pub score: f64,
+    pub skill_similarity: f64,
+    pub description_similarity: f64,
+    pub shared_skills: Vec<String>,
+    pub employers: Vec<String>,
+}
+
+pub fn related_projects(resume: &Resume, index: &ResumeIndex) -> BTreeMap<String, Vec<RelatedProject>> {
+    let tfidf = TfIdf::fit(resume.projects.iter().map(|p| p.description.as_str()));
+    let vectors: HashMap<&str, TermVector> = resume
+        .projects
+        .iter()
+        .map(|p| (p.id.as_str(), tfidf.vector(&p.description)))
+        .collect();
+    let skills: HashMap<&str, BTreeSet<&str>> = resume
+        .projects
+        .iter()
+        .map(|p| (p.id.as_str(), p.skills.iter().map(String::as_str).collect()))
+        .collect();
+
+    let employers = |project_id: &str| -> Vec<String> {
+        let mut employers: Vec<String> = index
+            .project_to_experiences
+            .get(project_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|id| index.experience_lookup.get(id))
+            .map(|e| e.employer.clone())
+            .collect();
+        employers.sort();
+        employers.dedup();
+        employers
+    };
+
+    resume
+        .projects
+        .iter()
+        .map(|project| {
+            let mut related: Vec<RelatedProject> = resume
+                .projects
+                .iter()
+                .filter(|other| other.id != project.id)
+                .filter_map(|other| {
+                    let (a, b) = (&skills[project.id.as_str()], &skills[other.id.as_str()]);
+                    let skill_similarity = jaccard(a, b);
+                    let description_similarity =
+                        cosine(&vectors[project.id.as_str()], &vectors[other.id.as_str()]);
+                    let score = SKILL_WEIGHT * skill_similarity + DESCRIPTION_WEIGHT * description_similarity;
+                    if score <= 0.0 {
+                        return None;
+                    }
+                    Some(RelatedProject {
+                        id: other.id.clone(),
+                        title: other.title.clone(),
+                        score: round(score),
+                        skill_similarity: round(skill_similarity),
+                        description_similarity: round(description_similarity),
+                        shared_skills: a.intersection(b).map(|s| s.to_string()).collect(),
+                        employers: employers(&other.id),
+                    })
+                })
+                .collect();
+            related.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.id.cmp(&b.id)));
+            (project.id.clone(), related)
+        })
+        .collect()
+}
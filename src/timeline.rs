@@ -0,0 +1,282 @@
+use crate::{Experience, Resume, ResumeIndex, Skill, dates::parse_datetime};
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+use std::fmt;
+
+const DAYS_PER_YEAR: f64 = 365.25;
+const PRESENT: &str = "present";
+
+pub type Interval = (DateTime<Utc>, DateTime<Utc>);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillExperienceSummary {
+    #[serde(flatten)]
+    pub skill: Skill,
+    pub years_of_experience: f64,
+    pub last_used: Option<LastUsed>,
+    pub experiences: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LastUsed {
+    Date(DateTime<Utc>),
+    Present,
+}
+
+impl Serialize for LastUsed {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            LastUsed::Date(date) => date.serialize(serializer),
+            LastUsed::Present => serializer.serialize_str(PRESENT),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for LastUsed {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        if value == PRESENT {
+            return Ok(LastUsed::Present);
+        }
+        parse_datetime(&value)
+            .map(LastUsed::Date)
+            .ok_or_else(|| de::Error::custom(format!("expected a date or \"{}\", got \"{}\"", PRESENT, value)))
+    }
+}
+
+pub fn merge_intervals(mut intervals: Vec<Interval>) -> Vec<Interval> {
+    intervals.sort();
+    let mut merged: Vec<Interval> = Vec::new();
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+pub fn years_between(intervals: &[Interval]) -> f64 {
+    let days: i64 = intervals
+        .iter()
+        .map(|(start, end)| (*end - *start).num_days().max(0))
+        .sum();
+    (days as f64 / DAYS_PER_YEAR * 10.0).round() / 10.0
+}
+
+pub fn skill_experience_summaries(
+    resume: &Resume,
+    index: &ResumeIndex,
+    now: DateTime<Utc>,
+) -> Vec<SkillExperienceSummary> {
+    resume
+        .skills
+        .iter()
+        .map(|skill| {
+            let experiences = index
+                .skill_to_experiences
+                .get(&skill.id)
+                .cloned()
+                .unwrap_or_default();
+            let used_in: Vec<&Experience> = experiences
+                .iter()
+                .filter_map(|id| index.experience_lookup.get(id))
+                .collect();
+            // Ongoing roles count up to the time the resume is generated.
+            let intervals = used_in
+                .iter()
+                .map(|e| (e.start_date, e.end_date.unwrap_or(now).max(e.start_date)))
+                .collect();
+            let last_used = used_in
+                .iter()
+                .map(|e| e.end_date.map_or(LastUsed::Present, LastUsed::Date))
+                .max();
+            SkillExperienceSummary {
+                skill: skill.clone(),
+                years_of_experience: years_between(&merge_intervals(intervals)),
+                last_used,
+                experiences,
+            }
+        })
+        .collect()
+}
+
+pub fn skills_by_recency(mut summaries: Vec<SkillExperienceSummary>) -> Vec<SkillExperienceSummary> {
+    // Most recent first; skills never used in an experience go last.
+    summaries.sort_by(|a, b| {
+        b.last_used
+            .cmp(&a.last_used)
+            .then(b.years_of_experience.total_cmp(&a.years_of_experience))
+            .then(a.skill.id.cmp(&b.skill.id))
+    });
+    summaries
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TimelineEntry {
+    Experience {
+        id: String,
+        title: String,
+        employer: String,
+        start_date: DateTime<Utc>,
+        end_date: Option<DateTime<Utc>>,
+    },
+    Gap {
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+        days: i64,
+    },
+}
+
+fn sorted_experiences(resume: &Resume) -> Vec<&Experience> {
+    let mut experiences: Vec<&Experience> = resume.experiences.iter().collect();
+    experiences.sort_by_key(|e| (e.start_date, e.end_date.is_none(), e.end_date));
+    experiences
+}
+
+pub fn active_years(resume: &Resume, now: DateTime<Utc>) -> Vec<i32> {
+    match resume.experiences.iter().map(|e| e.start_date).min() {
+        Some(earliest) => (earliest.year()..=now.year()).collect(),
+        None => Vec::new(),
+    }
+}
+
+pub fn experiences_active_in_year(resume: &Resume, year: i32, now: DateTime<Utc>) -> Vec<&Experience> {
+    // Years chrono cannot represent have no experiences.
+    let start_of = |year: i32| Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0).single();
+    let (Some(year_start), Some(next_year_start)) = (start_of(year), year.checked_add(1).and_then(start_of)) else {
+        return Vec::new();
+    };
+    sorted_experiences(resume)
+        .into_iter()
+        .filter(|e| e.start_date < next_year_start && e.end_date.unwrap_or(now) >= year_start)
+        .collect()
+}
+
+pub fn career_timeline(resume: &Resume, now: DateTime<Utc>) -> Vec<TimelineEntry> {
+    let mut timeline = Vec::new();
+    let mut covered_until: Option<DateTime<Utc>> = None;
+    for experience in sorted_experiences(resume) {
+        if let Some(until) = covered_until {
+            let days = (experience.start_date - until).num_days();
+            if days > 0 {
+                timeline.push(TimelineEntry::Gap {
+                    start_date: until,
+                    end_date: experience.start_date,
+                    days,
+                });
+            }
+        }
+        let end = experience.end_date.unwrap_or(now);
+        covered_until = Some(covered_until.map_or(end, |until| until.max(end)));
+        timeline.push(TimelineEntry::Experience {
+            id: experience.id.clone(),
+            title: experience.title.clone(),
+            employer: experience.employer.clone(),
+            start_date: experience.start_date,
+            end_date: experience.end_date,
+        });
+    }
+    timeline
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CareerGap {
+    pub after: String,
+    pub before: String,
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub days: i64,
+}
+
+impl fmt::Display for CareerGap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "gap of {} days between '{}' ({}) and '{}' ({})",
+            self.days,
+            self.after,
+            self.start_date.format("%Y-%m-%d"),
+            self.before,
+            self.end_date.format("%Y-%m-%d")
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CareerOverlap {
+    pub experiences: [String; 2],
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub days: i64,
+}
+
+impl fmt::Display for CareerOverlap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "'{}' and '{}' overlap by {} days ({} to {})",
+            self.experiences[0],
+            self.experiences[1],
+            self.days,
+            self.start_date.format("%Y-%m-%d"),
+            self.end_date.format("%Y-%m-%d")
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CareerGaps {
+    pub threshold_days: i64,
+    pub gaps: Vec<CareerGap>,
+    pub overlaps: Vec<CareerOverlap>,
+}
+
+pub fn career_gaps(resume: &Resume, now: DateTime<Utc>, threshold_days: i64) -> CareerGaps {
+    let experiences = sorted_experiences(resume);
+    let end_of = |e: &Experience| e.end_date.unwrap_or(now).max(e.start_date);
+
+    let mut gaps = Vec::new();
+    let mut latest: Option<&Experience> = None;
+    for experience in &experiences {
+        if let Some(previous) = latest {
+            let days = (experience.start_date - end_of(previous)).num_days();
+            if days > threshold_days {
+                gaps.push(CareerGap {
+                    after: previous.id.clone(),
+                    before: experience.id.clone(),
+                    start_date: end_of(previous),
+                    end_date: experience.start_date,
+                    days,
+                });
+            }
+        }
+        if latest.is_none_or(|previous| end_of(experience) > end_of(previous)) {
+            latest = Some(experience);
+        }
+    }
+
+    let mut overlaps = Vec::new();
+    for (i, a) in experiences.iter().enumerate() {
+        for b in &experiences[i + 1..] {
+            let start = b.start_date;
+            let end = end_of(a).min(end_of(b));
+            let days = (end - start).num_days();
+            if days > 0 {
+                overlaps.push(CareerOverlap {
+                    experiences: [a.id.clone(), b.id.clone()],
+                    start_date: start,
+                    end_date: end,
+                    days,
+                });
+            }
+        }
+    }
+
+    CareerGaps {
+        threshold_days,
+        gaps,
+        overlaps,
+    }
+}
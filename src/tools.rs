@@ -0,0 +1,606 @@
+use crate::{
+    AliasMap, CareerGaps, Certification, Education, Error, Experience, GeneratorOptions, JobMatch,
+    MCPToolResult, MCPToolSchema, PersonalInfo, Project, Publication, PublicationKind, RelatedProject,
+    RelatedSkill, Result, Resume, ResumeIndex, SearchIndex, SearchResults, Skill, SkillCluster,
+    SkillExperienceSummary, SkillGraph, TimelineEntry, active_years, build_alias_map,
+    build_search_index, build_skill_graph, career_gaps, career_timeline, experiences_active_in_year,
+    find_skill_clusters, output_schema, related_projects, schema::resume_indexes_schema,
+    skill_experience_summaries, skills_by_recency,
+};
+use chrono::{DateTime, Utc};
+use serde_json::{Value, json};
+use std::cell::OnceCell;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// A tool published in the manifest, whose results are pre-computed into `tools/`.
+///
+/// Register one with `StaticGenerator::with_tool`; a tool with the name of an already
+/// registered one replaces it.
+pub trait StaticTool {
+    /// The tool name. It is also the name of its file or directory under `tools/`, so it
+    /// should only contain letters, digits, `_` and `-`.
+    fn name(&self) -> &str;
+
+    fn description(&self) -> &str;
+
+    /// JSON Schema of the tool input. The order of its properties is the order of the
+    /// values passed to `call` and of the directories in the result paths.
+    fn input_schema(&self) -> Value;
+
+    /// JSON Schema of `structuredContent`, if the tool declares one. It must describe an
+    /// object; see `output_schema` for wrapping other types.
+    fn output_schema(&self) -> Option<Value> {
+        None
+    }
+
+    /// Whether results are written to `tools/`. A tool that is not pre-computed is only
+    /// listed in the manifest and left to the bridge; `arguments` and `call` are never used
+    /// during generation, and `call` should return `Error::NotPrecomputed`.
+    fn precomputed(&self) -> bool {
+        true
+    }
+
+    /// Called by `StaticGenerator::generate` after validation and before anything is
+    /// written, so that a misconfigured tool fails the build instead of writing a partial
+    /// site.
+    fn check(&self, _context: &ToolContext) -> Result<()> {
+        Ok(())
+    }
+
+    /// Every combination of argument values to pre-compute, each in input schema order.
+    /// A combination `[a, b]` is written to `tools/{name}/{a}/{b}.json`, and the empty
+    /// combination to `tools/{name}.json`. Values become file names, so they must not
+    /// contain `/` or be `.` or `..`.
+    fn arguments(&self, context: &ToolContext) -> Vec<Vec<String>>;
+
+    /// Computes the result for one combination of argument values, in input schema order.
+    /// Unknown values should return `Error::UnknownArgument` rather than panic.
+    fn call(&self, context: &ToolContext, arguments: &[String]) -> Result<MCPToolResult>;
+
+    /// The manifest entry, built from the methods above.
+    fn schema(&self) -> MCPToolSchema {
+        MCPToolSchema {
+            name: self.name().to_string(),
+            description: self.description().to_string(),
+            input_schema: self.input_schema(),
+            output_schema: self.output_schema(),
+        }
+    }
+}
+
+pub struct ToolContext<'a> {
+    pub resume: &'a Resume,
+    pub index: &'a ResumeIndex,
+    pub options: &'a GeneratorOptions,
+    pub generated_at: DateTime<Utc>,
+    aliases: OnceCell<AliasMap>,
+    skill_graph: OnceCell<SkillGraph>,
+    related_projects: OnceCell<BTreeMap<String, Vec<RelatedProject>>>,
+    search_index: OnceCell<SearchIndex>,
+    skill_summaries: OnceCell<Vec<SkillExperienceSummary>>,
+}
+
+impl<'a> ToolContext<'a> {
+    pub fn new(
+        resume: &'a Resume,
+        index: &'a ResumeIndex,
+        options: &'a GeneratorOptions,
+        generated_at: DateTime<Utc>,
+    ) -> Self {
+        ToolContext {
+            resume,
+            index,
+            options,
+            generated_at,
+            aliases: OnceCell::new(),
+            skill_graph: OnceCell::new(),
+            related_projects: OnceCell::new(),
+            search_index: OnceCell::new(),
+            skill_summaries: OnceCell::new(),
+        }
+    }
+
+    pub fn aliases(&self) -> &AliasMap {
+        self.aliases.get_or_init(|| build_alias_map(self.resume))
+    }
+
+    pub fn skill_graph(&self) -> &SkillGraph {
+        self.skill_graph
+            .get_or_init(|| build_skill_graph(self.resume, self.index))
+    }
+
+    pub fn related_projects(&self) -> &BTreeMap<String, Vec<RelatedProject>> {
+        self.related_projects
+            .get_or_init(|| related_projects(self.resume, self.index))
+    }
+
+    pub fn search_index(&self) -> &SearchIndex {
+        self.search_index.get_or_init(|| build_search_index(self.resume))
+    }
+
+    pub fn skill_experience_summaries(&self) -> &[SkillExperienceSummary] {
+        self.skill_summaries
+            .get_or_init(|| skill_experience_summaries(self.resume, self.index, self.generated_at))
+    }
+
+    // Skill ids, followed by every alias so that tools can be called by name.
+    pub fn skill_arguments(&self) -> Vec<Vec<String>> {
+        let ids = self.resume.skills.iter().map(|s| s.id.clone());
+        ids.chain(self.aliases().skills.keys().cloned())
+            .map(|id| vec![id])
+            .collect()
+    }
+
+    pub fn project_arguments(&self) -> Vec<Vec<String>> {
+        let ids = self.resume.projects.iter().map(|p| p.id.clone());
+        ids.chain(self.aliases().projects.keys().cloned())
+            .map(|id| vec![id])
+            .collect()
+    }
+}
+
+type Arguments = fn(&ToolContext) -> Vec<Vec<String>>;
+type Call = fn(&ToolContext, &[String]) -> Result<MCPToolResult>;
+
+struct BuiltinTool {
+    name: &'static str,
+    description: &'static str,
+    input_schema: Value,
+    output_schema: Value,
+    precomputed: bool,
+    arguments: Arguments,
+    call: Call,
+}
+
+impl StaticTool for BuiltinTool {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn description(&self) -> &str {
+        self.description
+    }
+
+    fn input_schema(&self) -> Value {
+        self.input_schema.clone()
+    }
+
+    fn output_schema(&self) -> Option<Value> {
+        Some(self.output_schema.clone())
+    }
+
+    fn precomputed(&self) -> bool {
+        self.precomputed
+    }
+
+    fn arguments(&self, context: &ToolContext) -> Vec<Vec<String>> {
+        (self.arguments)(context)
+    }
+
+    fn call(&self, context: &ToolContext, arguments: &[String]) -> Result<MCPToolResult> {
+        (self.call)(context, arguments)
+    }
+}
+
+fn tool(
+    name: &'static str,
+    description: &'static str,
+    input_schema: Value,
+    output_schema: Value,
+    arguments: Arguments,
+    call: Call,
+) -> Box<dyn StaticTool> {
+    Box::new(BuiltinTool {
+        name,
+        description,
+        input_schema,
+        output_schema,
+        precomputed: true,
+        arguments,
+        call,
+    })
+}
+
+fn no_input() -> Value {
+    json!({
+        "type": "object",
+        "properties": {},
+        "required": []
+    })
+}
+
+fn string_input(name: &str, description: &str) -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            name: {"type": "string", "description": description}
+        },
+        "required": [name]
+    })
+}
+
+fn skill_input() -> Value {
+    string_input("skill_id", "Skill ID, name or alias")
+}
+
+fn project_input() -> Value {
+    string_input("project_id", "Project ID, title or alias")
+}
+
+fn no_arguments(_: &ToolContext) -> Vec<Vec<String>> {
+    vec![vec![]]
+}
+
+fn skill_arguments(context: &ToolContext) -> Vec<Vec<String>> {
+    context.skill_arguments()
+}
+
+fn project_arguments(context: &ToolContext) -> Vec<Vec<String>> {
+    context.project_arguments()
+}
+
+// A missing argument is looked up as the empty string, which never matches.
+fn argument(arguments: &[String], index: usize) -> &str {
+    arguments.get(index).map(String::as_str).unwrap_or_default()
+}
+
+fn unknown(argument: &str, value: &str) -> Error {
+    Error::UnknownArgument {
+        argument: argument.to_string(),
+        value: value.to_string(),
+    }
+}
+
+fn find<'a, T>(entries: &'a HashMap<String, T>, name: &str, value: &str) -> Result<&'a T> {
+    entries.get(value).ok_or_else(|| unknown(name, value))
+}
+
+fn skill<'a>(context: &'a ToolContext, arguments: &'a [String]) -> Result<&'a Skill> {
+    let name = argument(arguments, 0);
+    let id = context.aliases().resolve_skill(name);
+    context.index.skill_lookup.get(id).ok_or_else(|| unknown("skill_id", name))
+}
+
+fn project<'a>(context: &'a ToolContext, arguments: &'a [String]) -> Result<&'a Project> {
+    let name = argument(arguments, 0);
+    let id = context.aliases().resolve_project(name);
+    context.index.project_lookup.get(id).ok_or_else(|| unknown("project_id", name))
+}
+
+fn lookup<'a, T>(ids: Option<&'a Vec<String>>, entries: &'a HashMap<String, T>) -> Vec<&'a T> {
+    ids.into_iter()
+        .flatten()
+        .filter_map(|id| entries.get(id))
+        .collect()
+}
+
+pub fn builtin_tools() -> Vec<Box<dyn StaticTool>> {
+    vec![
+        tool(
+            "get_skills_for_project",
+            "Get all skills used in a specific project",
+            project_input(),
+            output_schema::<Vec<Skill>>(),
+            project_arguments,
+            |context, arguments| {
+                let project = project(context, arguments)?;
+                MCPToolResult::new(&lookup(Some(&project.skills), &context.index.skill_lookup))
+            },
+        ),
+        tool(
+            "get_projects_using_skill",
+            "Get all projects that use a specific skill",
+            skill_input(),
+            output_schema::<Vec<Project>>(),
+            skill_arguments,
+            |context, arguments| {
+                let ids = context.index.skill_to_projects.get(&skill(context, arguments)?.id);
+                MCPToolResult::new(&lookup(ids, &context.index.project_lookup))
+            },
+        ),
+        tool(
+            "get_experiences_using_skill",
+            "Get all experiences that involve a specific skill",
+            skill_input(),
+            output_schema::<Vec<Experience>>(),
+            skill_arguments,
+            |context, arguments| {
+                let ids = context.index.skill_to_experiences.get(&skill(context, arguments)?.id);
+                MCPToolResult::new(&lookup(ids, &context.index.experience_lookup))
+            },
+        ),
+        tool(
+            "get_shared_skills",
+            "Get skills shared between two projects",
+            json!({
+                "type": "object",
+                "properties": {
+                    "project_a": {"type": "string", "description": "First project ID"},
+                    "project_b": {"type": "string", "description": "Second project ID"}
+                },
+                "required": ["project_a", "project_b"]
+            }),
+            output_schema::<Vec<Skill>>(),
+            |context| {
+                let projects = &context.resume.projects;
+                projects
+                    .iter()
+                    .flat_map(|a| {
+                        projects
+                            .iter()
+                            .filter(move |b| b.id != a.id)
+                            .map(move |b| vec![a.id.clone(), b.id.clone()])
+                    })
+                    .collect()
+            },
+            |context, arguments| {
+                let lookup = &context.index.project_lookup;
+                let skills_a: HashSet<&String> =
+                    find(lookup, "project_a", argument(arguments, 0))?.skills.iter().collect();
+                let skills_b: HashSet<&String> =
+                    find(lookup, "project_b", argument(arguments, 1))?.skills.iter().collect();
+                let shared: Vec<&Skill> = skills_a
+                    .intersection(&skills_b)
+                    .filter_map(|id| context.index.skill_lookup.get(*id))
+                    .collect();
+                MCPToolResult::new(&shared)
+            },
+        ),
+        tool(
+            "find_skill_clusters",
+            "Find sets of skills used together in at least the configured number of projects, with their support counts and supporting projects",
+            no_input(),
+            output_schema::<Vec<SkillCluster>>(),
+            no_arguments,
+            |context, _| {
+                MCPToolResult::new(&find_skill_clusters(
+                    &context.resume.projects,
+                    context.options.cluster_min_support,
+                    context.options.cluster_max_size,
+                ))
+            },
+        ),
+        tool(
+            "get_related_skills",
+            "Get the skills used alongside a specific skill, strongest first, weighted by the number of projects and experiences using both",
+            skill_input(),
+            output_schema::<Vec<RelatedSkill>>(),
+            skill_arguments,
+            |context, arguments| {
+                let skill = skill(context, arguments)?;
+                MCPToolResult::new(&context.skill_graph().related(&skill.id))
+            },
+        ),
+        tool(
+            "get_related_projects",
+            "Get other projects similar to a specific project, ranked by shared skills and description similarity",
+            project_input(),
+            output_schema::<Vec<RelatedProject>>(),
+            project_arguments,
+            |context, arguments| {
+                let project = project(context, arguments)?;
+                let related = context.related_projects().get(&project.id).cloned().unwrap_or_default();
+                MCPToolResult::new(&related)
+            },
+        ),
+        Box::new(BuiltinTool {
+            name: "match_job_description",
+            description: "Score how well the resume fits a job description. Not pre-computed: the bridge scores the text against resume://match/vocabulary and resume://match/vectors",
+            input_schema: string_input("job_description", "Full text of the job description"),
+            output_schema: output_schema::<JobMatch>(),
+            precomputed: false,
+            arguments: |_| vec![],
+            call: |_, _| {
+                Err(Error::NotPrecomputed {
+                    tool: "match_job_description".to_string(),
+                })
+            },
+        }),
+        tool(
+            "get_basic_info",
+            "Get basic personal information",
+            no_input(),
+            output_schema::<PersonalInfo>(),
+            no_arguments,
+            |context, _| MCPToolResult::new(&context.resume.info),
+        ),
+        tool(
+            "get_resume_indexes",
+            "Get index-level details for skills, projects, and experiences",
+            no_input(),
+            resume_indexes_schema(),
+            no_arguments,
+            |context, _| {
+                let index = context.index;
+                MCPToolResult::new(&json!({
+                    "skill_to_projects": &index.skill_to_projects,
+                    "skill_to_experiences": &index.skill_to_experiences,
+                    "project_to_experiences": &index.project_to_experiences,
+                    "skill_to_education": &index.skill_to_education,
+                    "skill_to_certifications": &index.skill_to_certifications,
+                    "skill_to_publications": &index.skill_to_publications,
+                }))
+            },
+        ),
+        tool(
+            "get_experience_details",
+            "Get detailed information about a specific experience",
+            string_input("experience_id", "Experience ID"),
+            output_schema::<Experience>(),
+            |context| context.resume.experiences.iter().map(|e| vec![e.id.clone()]).collect(),
+            |context, arguments| {
+                let experience = find(&context.index.experience_lookup, "experience_id", argument(arguments, 0))?;
+                MCPToolResult::new(experience)
+            },
+        ),
+        tool(
+            "get_project_details",
+            "Get detailed information about a specific project",
+            project_input(),
+            output_schema::<Project>(),
+            project_arguments,
+            |context, arguments| MCPToolResult::new(project(context, arguments)?),
+        ),
+        tool(
+            "get_active_certifications",
+            "Get certifications that were issued and not yet expired when the resume was generated",
+            no_input(),
+            output_schema::<Vec<Certification>>(),
+            no_arguments,
+            |context, _| {
+                let active: Vec<&Certification> = context
+                    .resume
+                    .certifications
+                    .iter()
+                    .filter(|certification| certification.is_active_at(context.generated_at))
+                    .collect();
+                MCPToolResult::new(&active)
+            },
+        ),
+        tool(
+            "get_education_details",
+            "Get detailed information about a specific education entry",
+            string_input("education_id", "Education ID"),
+            output_schema::<Education>(),
+            |context| context.resume.education.iter().map(|e| vec![e.id.clone()]).collect(),
+            |context, arguments| {
+                let education = find(&context.index.education_lookup, "education_id", argument(arguments, 0))?;
+                MCPToolResult::new(education)
+            },
+        ),
+        tool(
+            "get_education_using_skill",
+            "Get all education entries that involve a specific skill",
+            skill_input(),
+            output_schema::<Vec<Education>>(),
+            skill_arguments,
+            |context, arguments| {
+                let ids = context.index.skill_to_education.get(&skill(context, arguments)?.id);
+                MCPToolResult::new(&lookup(ids, &context.index.education_lookup))
+            },
+        ),
+        tool(
+            "get_skill_experience_summary",
+            "Get a skill's declared proficiency and years of experience, computed from the experiences that use it",
+            skill_input(),
+            output_schema::<SkillExperienceSummary>(),
+            skill_arguments,
+            |context, arguments| {
+                let skill = skill(context, arguments)?;
+                let summaries = context.skill_experience_summaries();
+                let summary = summaries
+                    .iter()
+                    .find(|summary| summary.skill.id == skill.id)
+                    .ok_or_else(|| unknown("skill_id", argument(arguments, 0)))?;
+                MCPToolResult::new(summary)
+            },
+        ),
+        tool(
+            "get_skills_by_recency",
+            "Get all skills ordered by when they were last used, most recent first",
+            no_input(),
+            output_schema::<Vec<SkillExperienceSummary>>(),
+            no_arguments,
+            |context, _| MCPToolResult::new(&skills_by_recency(context.skill_experience_summaries().to_vec())),
+        ),
+        tool(
+            "get_experiences_active_in_year",
+            "Get all experiences that were ongoing at some point during a specific year",
+            json!({
+                "type": "object",
+                "properties": {
+                    "year": {"type": "integer", "description": "Calendar year, e.g. 2019"}
+                },
+                "required": ["year"]
+            }),
+            output_schema::<Vec<Experience>>(),
+            |context| {
+                active_years(context.resume, context.generated_at)
+                    .into_iter()
+                    .map(|year| vec![year.to_string()])
+                    .collect()
+            },
+            |context, arguments| {
+                let value = argument(arguments, 0);
+                let year: i32 = value.parse().map_err(|_| unknown("year", value))?;
+                MCPToolResult::new(&experiences_active_in_year(context.resume, year, context.generated_at))
+            },
+        ),
+        tool(
+            "get_career_timeline",
+            "Get all experiences in chronological order, with the gaps between them",
+            no_input(),
+            output_schema::<Vec<TimelineEntry>>(),
+            no_arguments,
+            |context, _| MCPToolResult::new(&career_timeline(context.resume, context.generated_at)),
+        ),
+        tool(
+            "get_career_gaps",
+            "Get gaps between experiences longer than the configured threshold, and experiences that overlap",
+            no_input(),
+            output_schema::<CareerGaps>(),
+            no_arguments,
+            |context, _| {
+                MCPToolResult::new(&career_gaps(
+                    context.resume,
+                    context.generated_at,
+                    context.options.gap_threshold_days,
+                ))
+            },
+        ),
+        tool(
+            "search_resume",
+            "Find projects, experiences and skills mentioning a keyword, ranked by relevance. The term is matched case-insensitively as a single word",
+            string_input("term", "Lowercase keyword, e.g. kubernetes"),
+            output_schema::<SearchResults>(),
+            |context| context.search_index().terms.keys().map(|term| vec![term.clone()]).collect(),
+            |context, arguments| {
+                let term = argument(arguments, 0);
+                MCPToolResult::new(context.search_index().terms.get(term).ok_or_else(|| unknown("term", term))?)
+            },
+        ),
+        tool(
+            "get_publications_by_kind",
+            "Get all publications of a specific kind, newest first",
+            json!({
+                "type": "object",
+                "properties": {
+                    "kind": {
+                        "type": "string",
+                        "enum": ["paper", "talk", "patent", "blog"],
+                        "description": "Publication kind"
+                    }
+                },
+                "required": ["kind"]
+            }),
+            output_schema::<Vec<Publication>>(),
+            |_| PublicationKind::ALL.iter().map(|kind| vec![kind.as_str().to_string()]).collect(),
+            |context, arguments| {
+                let value = argument(arguments, 0);
+                let kind = PublicationKind::ALL
+                    .into_iter()
+                    .find(|kind| kind.as_str() == value)
+                    .ok_or_else(|| unknown("kind", value))?;
+                let mut publications: Vec<&Publication> = context
+                    .resume
+                    .publications
+                    .iter()
+                    .filter(|publication| publication.kind == kind)
+                    .collect();
+                publications.sort_by_key(|publication| std::cmp::Reverse(publication.date));
+                MCPToolResult::new(&publications)
+            },
+        ),
+        tool(
+            "get_publications_using_skill",
+            "Get all publications that involve a specific skill",
+            skill_input(),
+            output_schema::<Vec<Publication>>(),
+            skill_arguments,
+            |context, arguments| {
+                let ids = context.index.skill_to_publications.get(&skill(context, arguments)?.id);
+                MCPToolResult::new(&lookup(ids, &context.index.publication_lookup))
+            },
+        ),
+    ]
+}
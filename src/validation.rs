@@ -0,0 +1,382 @@
+use crate::{Origins, Resume, aliases::is_file_safe};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueKind {
+    DanglingReference,
+    DuplicateId,
+    OrphanedSkill,
+    OrphanedProject,
+    InvalidDateRange,
+    AmbiguousAlias,
+    UnsafeId,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationIssue {
+    pub severity: Severity,
+    pub kind: IssueKind,
+    pub path: String,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin: Option<PathBuf>,
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(origin) = &self.origin {
+            write!(f, "{}: ", origin.display())?;
+        }
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ValidationReport {
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    pub fn errors(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues.iter().filter(|i| i.severity == Severity::Error)
+    }
+
+    pub fn warnings(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues.iter().filter(|i| i.severity == Severity::Warning)
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.errors().next().is_some()
+    }
+
+    fn error(&mut self, kind: IssueKind, origin: Option<&Path>, path: String, message: String) {
+        self.issues.push(ValidationIssue {
+            severity: Severity::Error,
+            kind,
+            path,
+            message,
+            origin: origin.map(Path::to_path_buf),
+        });
+    }
+
+    fn warning(&mut self, kind: IssueKind, origin: Option<&Path>, path: String, message: String) {
+        self.issues.push(ValidationIssue {
+            severity: Severity::Warning,
+            kind,
+            path,
+            message,
+            origin: origin.map(Path::to_path_buf),
+        });
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let errors: Vec<&ValidationIssue> = self.errors().collect();
+        write!(f, "resume failed validation with {} error(s)", errors.len())?;
+        for error in errors {
+            write!(f, "\n  {}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationReport {}
+
+fn check_ids<'a>(
+    report: &mut ValidationReport,
+    origins: &Origins,
+    (kind, section): (&str, &str),
+    ids: impl Iterator<Item = &'a str>,
+) -> HashSet<&'a str> {
+    let mut first_seen: HashMap<&str, usize> = HashMap::new();
+    for (i, id) in ids.enumerate() {
+        // Ids become file names, so one that could escape its directory would break generation.
+        if !is_file_safe(id) {
+            report.error(
+                IssueKind::UnsafeId,
+                origins.get(kind, id),
+                format!("resume.{}[{}].id", section, i),
+                format!("id '{}' cannot be used as a file name", id),
+            );
+        }
+        if let Some(first) = first_seen.get(id) {
+            report.error(
+                IssueKind::DuplicateId,
+                origins.get(kind, id),
+                format!("resume.{}[{}].id", section, i),
+                format!("duplicate id '{}' (first defined at resume.{}[{}])", id, section, first),
+            );
+        } else {
+            first_seen.insert(id, i);
+        }
+    }
+    first_seen.into_keys().collect()
+}
+
+fn check_aliases<'a>(
+    report: &mut ValidationReport,
+    origins: &Origins,
+    (kind, section): (&str, &str),
+    entries: impl Iterator<Item = (&'a str, &'a [String])>,
+) {
+    let entries: Vec<(&str, &[String])> = entries.collect();
+    let mut claimed: HashMap<String, &str> = entries
+        .iter()
+        .map(|(id, _)| (id.to_lowercase(), *id))
+        .collect();
+    for (i, (id, aliases)) in entries.iter().enumerate() {
+        for (j, alias) in aliases.iter().enumerate() {
+            match claimed.get(&alias.to_lowercase()) {
+                Some(owner) if owner != id => report.error(
+                    IssueKind::AmbiguousAlias,
+                    origins.get(kind, id),
+                    format!("resume.{}[{}].aliases[{}]", section, i, j),
+                    format!("alias '{}' of '{}' already refers to '{}'", alias, id, owner),
+                ),
+                _ => {
+                    claimed.insert(alias.to_lowercase(), id);
+                }
+            }
+        }
+    }
+}
+
+pub fn validate(resume: &Resume) -> ValidationReport {
+    validate_with_origins(resume, &Origins::default())
+}
+
+// Like `validate`, but each issue also names the file its entry was loaded from.
+pub fn validate_with_origins(resume: &Resume, origins: &Origins) -> ValidationReport {
+    let mut report = ValidationReport::default();
+
+    check_ids(
+        &mut report,
+        origins,
+        ("experience", "experiences"),
+        resume.experiences.iter().map(|e| e.id.as_str()),
+    );
+    let project_ids = check_ids(
+        &mut report,
+        origins,
+        ("project", "projects"),
+        resume.projects.iter().map(|p| p.id.as_str()),
+    );
+    let skill_ids = check_ids(
+        &mut report,
+        origins,
+        ("skill", "skills"),
+        resume.skills.iter().map(|s| s.id.as_str()),
+    );
+    check_ids(
+        &mut report,
+        origins,
+        ("education", "education"),
+        resume.education.iter().map(|e| e.id.as_str()),
+    );
+    check_ids(
+        &mut report,
+        origins,
+        ("certification", "certifications"),
+        resume.certifications.iter().map(|c| c.id.as_str()),
+    );
+    check_ids(
+        &mut report,
+        origins,
+        ("publication", "publications"),
+        resume.publications.iter().map(|p| p.id.as_str()),
+    );
+    check_aliases(
+        &mut report,
+        origins,
+        ("project", "projects"),
+        resume.projects.iter().map(|p| (p.id.as_str(), p.aliases.as_slice())),
+    );
+    check_aliases(
+        &mut report,
+        origins,
+        ("skill", "skills"),
+        resume.skills.iter().map(|s| (s.id.as_str(), s.aliases.as_slice())),
+    );
+
+    let mut used_skills = HashSet::new();
+    for (i, project) in resume.projects.iter().enumerate() {
+        for (j, skill_id) in project.skills.iter().enumerate() {
+            used_skills.insert(skill_id.as_str());
+            if !skill_ids.contains(skill_id.as_str()) {
+                report.error(
+                    IssueKind::DanglingReference,
+                    origins.get("project", &project.id),
+                    format!("resume.projects[{}].skills[{}]", i, j),
+                    format!("project '{}' references unknown skill '{}'", project.id, skill_id),
+                );
+            }
+        }
+    }
+
+    let mut used_projects = HashSet::new();
+    for (i, experience) in resume.experiences.iter().enumerate() {
+        for (j, project_id) in experience.projects.iter().enumerate() {
+            used_projects.insert(project_id.as_str());
+            if !project_ids.contains(project_id.as_str()) {
+                report.error(
+                    IssueKind::DanglingReference,
+                    origins.get("experience", &experience.id),
+                    format!("resume.experiences[{}].projects[{}]", i, j),
+                    format!(
+                        "experience '{}' references unknown project '{}'",
+                        experience.id, project_id
+                    ),
+                );
+            }
+        }
+
+        if let Some(end_date) = experience.end_date
+            && end_date < experience.start_date
+        {
+            report.error(
+                IssueKind::InvalidDateRange,
+                origins.get("experience", &experience.id),
+                format!("resume.experiences[{}].end_date", i),
+                format!(
+                    "experience '{}' ends ({}) before it starts ({})",
+                    experience.id,
+                    end_date.to_rfc3339(),
+                    experience.start_date.to_rfc3339()
+                ),
+            );
+        }
+    }
+
+    for (i, education) in resume.education.iter().enumerate() {
+        for (j, project_id) in education.projects.iter().enumerate() {
+            used_projects.insert(project_id.as_str());
+            if !project_ids.contains(project_id.as_str()) {
+                report.error(
+                    IssueKind::DanglingReference,
+                    origins.get("education", &education.id),
+                    format!("resume.education[{}].projects[{}]", i, j),
+                    format!(
+                        "education '{}' references unknown project '{}'",
+                        education.id, project_id
+                    ),
+                );
+            }
+        }
+
+        for (j, skill_id) in education.skills.iter().enumerate() {
+            used_skills.insert(skill_id.as_str());
+            if !skill_ids.contains(skill_id.as_str()) {
+                report.error(
+                    IssueKind::DanglingReference,
+                    origins.get("education", &education.id),
+                    format!("resume.education[{}].skills[{}]", i, j),
+                    format!("education '{}' references unknown skill '{}'", education.id, skill_id),
+                );
+            }
+        }
+
+        if let (Some(start_date), Some(end_date)) = (education.start_date, education.end_date)
+            && end_date < start_date
+        {
+            report.error(
+                IssueKind::InvalidDateRange,
+                origins.get("education", &education.id),
+                format!("resume.education[{}].end_date", i),
+                format!(
+                    "education '{}' ends ({}) before it starts ({})",
+                    education.id,
+                    end_date.to_rfc3339(),
+                    start_date.to_rfc3339()
+                ),
+            );
+        }
+    }
+
+    for (i, certification) in resume.certifications.iter().enumerate() {
+        for (j, skill_id) in certification.skills.iter().enumerate() {
+            used_skills.insert(skill_id.as_str());
+            if !skill_ids.contains(skill_id.as_str()) {
+                report.error(
+                    IssueKind::DanglingReference,
+                    origins.get("certification", &certification.id),
+                    format!("resume.certifications[{}].skills[{}]", i, j),
+                    format!(
+                        "certification '{}' references unknown skill '{}'",
+                        certification.id, skill_id
+                    ),
+                );
+            }
+        }
+
+        if let Some(expiry_date) = certification.expiry_date
+            && expiry_date < certification.issued_date
+        {
+            report.error(
+                IssueKind::InvalidDateRange,
+                origins.get("certification", &certification.id),
+                format!("resume.certifications[{}].expiry_date", i),
+                format!(
+                    "certification '{}' expires ({}) before it is issued ({})",
+                    certification.id,
+                    expiry_date.to_rfc3339(),
+                    certification.issued_date.to_rfc3339()
+                ),
+            );
+        }
+    }
+
+    for (i, publication) in resume.publications.iter().enumerate() {
+        for (j, skill_id) in publication.skills.iter().enumerate() {
+            used_skills.insert(skill_id.as_str());
+            if !skill_ids.contains(skill_id.as_str()) {
+                report.error(
+                    IssueKind::DanglingReference,
+                    origins.get("publication", &publication.id),
+                    format!("resume.publications[{}].skills[{}]", i, j),
+                    format!(
+                        "publication '{}' references unknown skill '{}'",
+                        publication.id, skill_id
+                    ),
+                );
+            }
+        }
+    }
+
+    for (i, skill) in resume.skills.iter().enumerate() {
+        if !used_skills.contains(skill.id.as_str()) {
+            report.warning(
+                IssueKind::OrphanedSkill,
+                origins.get("skill", &skill.id),
+                format!("resume.skills[{}]", i),
+                format!("skill '{}' is not referenced by any other entry", skill.id),
+            );
+        }
+    }
+
+    for (i, project) in resume.projects.iter().enumerate() {
+        if !used_projects.contains(project.id.as_str()) {
+            report.warning(
+                IssueKind::OrphanedProject,
+                origins.get("project", &project.id),
+                format!("resume.projects[{}]", i),
+                format!("project '{}' is not part of any experience or education", project.id),
+            );
+        }
+    }
+
+    report
+}
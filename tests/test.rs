@@ -1,6 +1,5 @@
 use resume_smg::*;
 use chrono::{DateTime, Utc};
-use serde_json;
 use std::collections::HashMap;
 use std::fs;
 use tempfile::TempDir;
@@ -51,6 +50,7 @@ fn create_test_resume() -> Resume {
                 duration: Some("8 months".to_string()),
                 description: "Built scalable e-commerce platform with microservices".to_string(),
                 skills: vec!["rust".to_string(), "postgresql".to_string(), "docker".to_string()],
+                aliases: vec![],
             },
             Project {
                 id: "proj2".to_string(),
@@ -58,6 +58,7 @@ fn create_test_resume() -> Resume {
                 duration: Some("4 months".to_string()),
                 description: "Real-time data processing pipeline".to_string(),
                 skills: vec!["rust".to_string(), "kafka".to_string(), "redis".to_string()],
+                aliases: vec![],
             },
             Project {
                 id: "proj3".to_string(),
@@ -65,6 +66,7 @@ fn create_test_resume() -> Resume {
                 duration: Some("6 months".to_string()),
                 description: "REST API for mobile application".to_string(),
                 skills: vec!["python".to_string(), "postgresql".to_string(), "docker".to_string()],
+                aliases: vec![],
             },
         ],
         skills: vec![
@@ -73,44 +75,65 @@ fn create_test_resume() -> Resume {
                 name: "Rust".to_string(),
                 skill_type: "programming_language".to_string(),
                 category: "backend".to_string(),
+                proficiency: None,
+                aliases: vec![],
             },
             Skill {
                 id: "python".to_string(),
                 name: "Python".to_string(),
                 skill_type: "programming_language".to_string(),
                 category: "backend".to_string(),
+                proficiency: None,
+                aliases: vec![],
             },
             Skill {
                 id: "postgresql".to_string(),
                 name: "PostgreSQL".to_string(),
                 skill_type: "database".to_string(),
                 category: "backend".to_string(),
+                proficiency: None,
+                aliases: vec![],
             },
             Skill {
                 id: "docker".to_string(),
                 name: "Docker".to_string(),
                 skill_type: "tool".to_string(),
                 category: "devops".to_string(),
+                proficiency: None,
+                aliases: vec![],
             },
             Skill {
                 id: "kafka".to_string(),
                 name: "Apache Kafka".to_string(),
                 skill_type: "message_queue".to_string(),
                 category: "backend".to_string(),
+                proficiency: None,
+                aliases: vec![],
             },
             Skill {
                 id: "redis".to_string(),
                 name: "Redis".to_string(),
                 skill_type: "database".to_string(),
                 category: "backend".to_string(),
+                proficiency: None,
+                aliases: vec![],
             },
         ],
+        education: vec![],
+        certifications: vec![],
+        publications: vec![],
     }
 }
 
 fn create_test_config() -> Config {
     Config {
         resume: create_test_resume(),
+        include: vec![],
+        options: GeneratorOptions::default(),
+        prompts: default_prompts(),
+        custom_tools: vec![],
+        project_markdown: HashMap::new(),
+        origins: Origins::default(),
     }
 }
 
@@ -234,8 +257,8 @@ fn test_mcp_manifest_generation() {
     assert_eq!(manifest.protocol_version, "2025-06-18");
     assert_eq!(manifest.server_info.name, "static-resume-mcp");
     assert_eq!(manifest.server_info.version, "0.1.0");
-    assert_eq!(manifest.capabilities.resources.len(), 4);
-    assert_eq!(manifest.capabilities.tools.len(), 9); // Updated count for new tools
+    assert_eq!(manifest.capabilities.resources.len(), 14);
+    assert_eq!(manifest.capabilities.tools.len(), 23); // Updated count for new tools
     
     let resource_uris: Vec<String> = manifest.capabilities.resources
         .iter()
@@ -245,6 +268,16 @@ fn test_mcp_manifest_generation() {
     assert!(resource_uris.contains(&"resume://experiences".to_string()));
     assert!(resource_uris.contains(&"resume://projects".to_string()));
     assert!(resource_uris.contains(&"resume://skills".to_string()));
+    assert!(resource_uris.contains(&"resume://jsonresume".to_string()));
+
+    let templates: Vec<&str> = manifest.capabilities.resource_templates
+        .iter()
+        .map(|t| t.uri_template.as_str())
+        .collect();
+    assert_eq!(
+        templates,
+        vec!["resume://experiences/{id}", "resume://projects/{id}", "resume://skills/{id}"]
+    );
     
     let tool_names: Vec<String> = manifest.capabilities.tools
         .iter()
@@ -259,6 +292,20 @@ fn test_mcp_manifest_generation() {
     assert!(tool_names.contains(&"get_resume_indexes".to_string()));
     assert!(tool_names.contains(&"get_experience_details".to_string()));
     assert!(tool_names.contains(&"get_project_details".to_string()));
+    assert!(tool_names.contains(&"get_education_details".to_string()));
+    assert!(tool_names.contains(&"get_education_using_skill".to_string()));
+    assert!(tool_names.contains(&"get_active_certifications".to_string()));
+    assert!(tool_names.contains(&"get_skill_experience_summary".to_string()));
+    assert!(tool_names.contains(&"get_skills_by_recency".to_string()));
+    assert!(tool_names.contains(&"get_experiences_active_in_year".to_string()));
+    assert!(tool_names.contains(&"get_career_timeline".to_string()));
+    assert!(tool_names.contains(&"get_career_gaps".to_string()));
+    assert!(tool_names.contains(&"search_resume".to_string()));
+    assert!(tool_names.contains(&"get_related_skills".to_string()));
+    assert!(tool_names.contains(&"get_related_projects".to_string()));
+    assert!(tool_names.contains(&"match_job_description".to_string()));
+    assert!(tool_names.contains(&"get_publications_by_kind".to_string()));
+    assert!(tool_names.contains(&"get_publications_using_skill".to_string()));
 }
 
 #[test]
@@ -293,6 +340,33 @@ fn test_resource_generation() {
     assert_eq!(experiences[0].title, "Senior Software Engineer");
     assert_eq!(experiences[0].employer, "Tech Corp");
     assert_eq!(experiences[1].employer, "StartupCo");
+
+    let read_entity = |kind: &str, id: &str| -> MCPResourceContent {
+        let content = fs::read_to_string(format!("{}/resources/{}/{}.json", output_path, kind, id))
+            .expect("Failed to read entity resource");
+        serde_json::from_str(&content).expect("Failed to parse entity resource")
+    };
+
+    let project_resource = read_entity("projects", "proj2");
+    assert_eq!(project_resource.uri, "resume://projects/proj2");
+    let project: Project = serde_json::from_str(&project_resource.text).unwrap();
+    assert_eq!(project.title, resume.projects[1].title);
+    assert_eq!(project.skills, resume.projects[1].skills);
+
+    let experience_resource = read_entity("experiences", "exp1");
+    assert_eq!(experience_resource.uri, "resume://experiences/exp1");
+    let experience: Experience = serde_json::from_str(&experience_resource.text).unwrap();
+    assert_eq!(experience.employer, "Tech Corp");
+
+    let skill_resource = read_entity("skills", "rust");
+    assert_eq!(skill_resource.uri, "resume://skills/rust");
+    let summary: SkillExperienceSummary = serde_json::from_str(&skill_resource.text).unwrap();
+    assert_eq!(summary.skill.name, "Rust");
+    assert_eq!(summary.experiences, vec!["exp1"]);
+
+    for skill in &resume.skills {
+        assert!(fs::metadata(format!("{}/resources/skills/{}.json", output_path, skill.id)).is_ok());
+    }
 }
 
 #[test]
@@ -579,6 +653,9 @@ fn test_empty_resume_handling() {
         experiences: vec![],
         projects: vec![],
         skills: vec![],
+        education: vec![],
+        certifications: vec![],
+        publications: vec![],
     };
     
     let temp_dir = TempDir::new().expect("Failed to create temp directory");
@@ -607,6 +684,7 @@ fn test_skill_clustering_algorithm() {
             duration: Some("3 months".to_string()),
             description: "Test project".to_string(),
             skills: vec!["skill1".to_string(), "skill2".to_string(), "skill3".to_string()],
+            aliases: vec![],
         },
         Project {
             id: "proj_b".to_string(),
@@ -614,6 +692,7 @@ fn test_skill_clustering_algorithm() {
             duration: Some("4 months".to_string()),
             description: "Another test project".to_string(),
             skills: vec!["skill1".to_string(), "skill2".to_string(), "skill4".to_string()],
+            aliases: vec![],
         },
         Project {
             id: "proj_c".to_string(),
@@ -621,6 +700,7 @@ fn test_skill_clustering_algorithm() {
             duration: Some("2 months".to_string()),
             description: "Third test project".to_string(),
             skills: vec!["skill5".to_string()],
+            aliases: vec![],
         },
     ];
     
@@ -638,26 +718,21 @@ fn test_skill_clustering_algorithm() {
     let result: MCPToolResult = serde_json::from_str(&content)
         .expect("Failed to parse skill clusters result");
     
-    let clusters: HashMap<String, Vec<String>> = serde_json::from_str(&result.content[0].text)
+    let clusters: Vec<SkillCluster> = serde_json::from_str(&result.content[0].text)
         .expect("Failed to parse clusters data");
     
-    let pair_key = "skill1,skill2";
-    assert!(clusters.contains_key(pair_key), 
-            "Expected to find cluster with key '{}', found keys: {:?}", 
-            pair_key, clusters.keys().collect::<Vec<_>>());
-    
-    let projects_in_cluster = &clusters[pair_key];
-    assert_eq!(projects_in_cluster.len(), 2);
-    assert!(projects_in_cluster.contains(&"proj_a".to_string()));
-    assert!(projects_in_cluster.contains(&"proj_b".to_string()));
+    let pair = clusters
+        .iter()
+        .find(|c| c.skills == vec!["skill1", "skill2"])
+        .unwrap_or_else(|| panic!("Expected to find skill1,skill2 cluster, found: {:?}", clusters));
     
-    let full_set_a = "skill1,skill2,skill3";
-    let full_set_b = "skill1,skill2,skill4";
+    assert_eq!(pair.support, 2);
+    assert_eq!(pair.projects, vec!["proj_a", "proj_b"]);
     
-    assert!(!clusters.contains_key(full_set_a), 
-            "Full skill set '{}' should not be clustered (appears only once)", full_set_a);
-    assert!(!clusters.contains_key(full_set_b), 
-            "Full skill set '{}' should not be clustered (appears only once)", full_set_b);
+    for cluster in &clusters {
+        assert!(cluster.skills.len() < 3,
+                "Full skill set {:?} should not be clustered (appears only once)", cluster.skills);
+    }
 }
 
 #[test]
@@ -678,27 +753,27 @@ fn test_skill_clusters_identification() {
     let result: MCPToolResult = serde_json::from_str(&content)
         .expect("Failed to parse skill clusters result");
     
-    let clusters: HashMap<String, Vec<String>> = serde_json::from_str(&result.content[0].text)
+    let clusters: Vec<SkillCluster> = serde_json::from_str(&result.content[0].text)
         .expect("Failed to parse clusters data");
         
     assert!(!clusters.is_empty(), "Expected to find at least one skill cluster");
     
-    let docker_postgresql_key = "docker,postgresql";
-    if let Some(projects) = clusters.get(docker_postgresql_key) {
-        assert!(projects.len() >= 2, 
-                "Expected docker+postgresql to appear in at least 2 projects, found: {:?}", projects);
-        assert!(projects.contains(&"proj1".to_string()), 
+    if let Some(cluster) = clusters.iter().find(|c| c.skills == vec!["docker", "postgresql"]) {
+        assert!(cluster.projects.len() >= 2, 
+                "Expected docker+postgresql to appear in at least 2 projects, found: {:?}", cluster.projects);
+        assert!(cluster.projects.contains(&"proj1".to_string()), 
                 "Expected proj1 to use docker+postgresql");
-        assert!(projects.contains(&"proj3".to_string()), 
+        assert!(cluster.projects.contains(&"proj3".to_string()), 
                 "Expected proj3 to use docker+postgresql");
     } else {
-        panic!("Expected to find docker,postgresql cluster. Available clusters: {:?}", clusters.keys().collect::<Vec<_>>());
+        panic!("Expected to find docker,postgresql cluster. Available clusters: {:?}", clusters);
     }
     
-    for (skills, projects) in &clusters {
-        assert!(projects.len() > 1, 
-                "Cluster '{}' should have more than 1 project, found: {:?}", 
-                skills, projects);
+    for cluster in &clusters {
+        assert!(cluster.projects.len() > 1, 
+                "Cluster {:?} should have more than 1 project, found: {:?}", 
+                cluster.skills, cluster.projects);
+        assert_eq!(cluster.support, cluster.projects.len());
     }
 }
 
@@ -757,6 +832,7 @@ fn test_project_with_no_duration() {
         duration: None,
         description: "Contributed to various open source projects".to_string(),
         skills: vec!["rust".to_string(), "git".to_string()],
+        aliases: vec![],
     });
     
     let temp_dir = TempDir::new().expect("Failed to create temp directory");
@@ -834,4 +910,2202 @@ fn test_complete_tool_coverage() {
     assert!(fs::metadata(format!("{}/tools/get_project_details/proj1.json", output_path)).is_ok());
     assert!(fs::metadata(format!("{}/tools/get_project_details/proj2.json", output_path)).is_ok());
     assert!(fs::metadata(format!("{}/tools/get_project_details/proj3.json", output_path)).is_ok());
-}
\ No newline at end of file
+}
+#[test]
+fn test_validation_accepts_consistent_resume() {
+    let resume = create_test_resume();
+    let report = validate(&resume);
+
+    assert!(!report.has_errors(), "Unexpected errors: {:?}", report.issues);
+    assert_eq!(report.warnings().count(), 0);
+}
+
+#[test]
+fn test_validation_reports_integrity_issues() {
+    let mut resume = create_test_resume();
+    resume.projects[0].skills.push("cobol".to_string());
+    resume.experiences[1].projects.push("proj_missing".to_string());
+    resume.experiences[1].end_date = Some(
+        DateTime::parse_from_rfc3339("2019-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc),
+    );
+    resume.skills.push(Skill {
+        id: "rust".to_string(),
+        name: "Rust again".to_string(),
+        skill_type: "programming_language".to_string(),
+        category: "backend".to_string(),
+        proficiency: None,
+        aliases: vec![],
+    });
+    resume.skills.push(Skill {
+        id: "go".to_string(),
+        name: "Go".to_string(),
+        skill_type: "programming_language".to_string(),
+        category: "backend".to_string(),
+        proficiency: None,
+        aliases: vec![],
+    });
+
+    let report = validate(&resume);
+    assert!(report.has_errors());
+
+    let find = |kind: IssueKind| -> Vec<&ValidationIssue> {
+        report.issues.iter().filter(|i| i.kind == kind).collect()
+    };
+
+    let dangling = find(IssueKind::DanglingReference);
+    assert_eq!(dangling.len(), 2);
+    assert!(dangling.iter().any(|i| i.path == "resume.projects[0].skills[3]"));
+    assert!(dangling.iter().any(|i| i.path == "resume.experiences[1].projects[1]"));
+
+    let duplicates = find(IssueKind::DuplicateId);
+    assert_eq!(duplicates.len(), 1);
+    assert_eq!(duplicates[0].path, "resume.skills[6].id");
+
+    let dates = find(IssueKind::InvalidDateRange);
+    assert_eq!(dates.len(), 1);
+    assert_eq!(dates[0].path, "resume.experiences[1].end_date");
+
+    let orphans = find(IssueKind::OrphanedSkill);
+    assert_eq!(orphans.len(), 1);
+    assert_eq!(orphans[0].severity, Severity::Warning);
+    assert_eq!(orphans[0].path, "resume.skills[7]");
+}
+
+#[test]
+fn test_validation_rejects_unsafe_ids() {
+    let mut resume = create_test_resume();
+    resume.projects[0].id = "a/b".to_string();
+    resume.experiences[0].projects = vec!["a/b".to_string()];
+    resume.skills[1].id = "../../x".to_string();
+    resume.projects[1].skills = vec!["../../x".to_string()];
+
+    let report = validate(&resume);
+    let unsafe_ids: Vec<&str> = report
+        .errors()
+        .filter(|i| i.kind == IssueKind::UnsafeId)
+        .map(|i| i.path.as_str())
+        .collect();
+    assert_eq!(unsafe_ids, vec!["resume.projects[0].id", "resume.skills[1].id"]);
+
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let output_path = temp_dir.path().join("dist").to_str().unwrap().to_string();
+    let err = StaticGenerator::new(resume, output_path.clone())
+        .generate()
+        .expect_err("Unsafe ids should fail validation");
+    assert_eq!(err.exit_code(), 4);
+    assert!(fs::metadata(&output_path).is_err());
+}
+
+#[test]
+fn test_validation_errors_block_generation() {
+    let mut resume = create_test_resume();
+    resume.projects[0].skills.push("cobol".to_string());
+
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let output_path = temp_dir.path().join("dist").to_str().unwrap().to_string();
+
+    let generator = StaticGenerator::new(resume, output_path.clone());
+    assert!(generator.generate().is_err());
+    assert!(fs::metadata(format!("{}/mcp.json", output_path)).is_err());
+
+    let mut resume = create_test_resume();
+    resume.projects.push(Project {
+        id: "side_project".to_string(),
+        title: "Side Project".to_string(),
+        duration: None,
+        description: "Weekend hacking".to_string(),
+        skills: vec!["rust".to_string()],
+        aliases: vec![],
+    });
+    let generator = StaticGenerator::new(resume, output_path.clone());
+    generator.generate().expect("Warnings should not block generation");
+    assert!(fs::metadata(format!("{}/mcp.json", output_path)).is_ok());
+}
+
+#[test]
+fn test_load_config_errors() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+
+    let missing = temp_dir.path().join("missing.json");
+    let err = load_config(&missing).expect_err("Missing config should fail");
+    assert!(matches!(err, Error::ConfigRead { .. }));
+    assert_eq!(err.exit_code(), 2);
+
+    let broken = temp_dir.path().join("broken.json");
+    fs::write(&broken, "{\n  \"resume\": {\n    \"info\": 42\n  }\n}").unwrap();
+    let err = load_config(&broken).expect_err("Invalid config should fail");
+    match &err {
+        Error::Parse { path, line, column, .. } => {
+            assert_eq!(path, &broken);
+            assert_eq!(*line, 3);
+            assert!(*column > 0);
+        }
+        other => panic!("Expected parse error, got {:?}", other),
+    }
+    assert_eq!(err.exit_code(), 3);
+
+    let valid = temp_dir.path().join("config.json");
+    fs::write(&valid, serde_json::to_string(&create_test_config()).unwrap()).unwrap();
+    let config = load_config(&valid).expect("Failed to load valid config");
+    assert_eq!(config.resume.info.name, "Test User");
+}
+
+#[test]
+fn test_generation_error_variants() {
+    let mut resume = create_test_resume();
+    resume.projects[0].skills.push("cobol".to_string());
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let output_path = temp_dir.path().to_str().unwrap().to_string();
+
+    let err = StaticGenerator::new(resume, output_path).generate().unwrap_err();
+    assert!(matches!(err, Error::Validation(_)));
+    assert_eq!(err.exit_code(), 4);
+
+    let blocker = temp_dir.path().join("not_a_directory");
+    fs::write(&blocker, "").unwrap();
+    let output_path = blocker.join("dist").to_str().unwrap().to_string();
+    let err = StaticGenerator::new(create_test_resume(), output_path.clone())
+        .generate()
+        .unwrap_err();
+    match &err {
+        Error::Io { path, .. } => assert_eq!(path.to_str().unwrap(), output_path),
+        other => panic!("Expected I/O error, got {:?}", other),
+    }
+    assert_eq!(err.exit_code(), 5);
+}
+
+const JSON_RESUME: &str = r#"{
+  "basics": {
+    "name": "Jordan Doe",
+    "email": "jordan@example.com",
+    "phone": "+1-555-0100",
+    "url": "https://jordan.dev",
+    "location": { "city": "Austin", "region": "TX", "countryCode": "US" },
+    "profiles": [
+      { "network": "GitHub", "username": "jordan", "url": "https://github.com/jordan" }
+    ]
+  },
+  "work": [
+    {
+      "name": "Acme Corp",
+      "position": "Backend Engineer",
+      "startDate": "2019-03",
+      "endDate": "2021-06-15",
+      "summary": "Owned the billing service written in Rust.",
+      "highlights": ["Moved deployments to Kubernetes"]
+    },
+    {
+      "name": "Globex",
+      "position": "Staff Engineer",
+      "startDate": "2021-07-01"
+    }
+  ],
+  "projects": [
+    {
+      "name": "Search Platform",
+      "description": "Elasticsearch based search.",
+      "keywords": ["Rust", "Elasticsearch"],
+      "entity": "Globex"
+    }
+  ],
+  "skills": [
+    { "name": "Backend", "level": "Master", "keywords": ["Rust", "PostgreSQL"] },
+    { "name": "DevOps", "keywords": ["Kubernetes"] }
+  ],
+  "education": [
+    {
+      "institution": "State University",
+      "area": "Computer Science",
+      "studyType": "Bachelor",
+      "startDate": "2014-09",
+      "endDate": "2018-06",
+      "score": "3.8",
+      "courses": ["Databases with PostgreSQL"]
+    }
+  ],
+  "certificates": [
+    {
+      "name": "Certified Kubernetes Administrator",
+      "date": "2022-05-10",
+      "issuer": "CNCF"
+    }
+  ],
+  "publications": [
+    {
+      "name": "Scaling PostgreSQL at Acme",
+      "publisher": "PGConf",
+      "releaseDate": "2020-11",
+      "summary": "Partitioning strategies for billing data."
+    }
+  ]
+}"#;
+
+#[test]
+fn test_json_resume_import() {
+    let json_resume: JsonResume = serde_json::from_str(JSON_RESUME).expect("Failed to parse JSON Resume");
+    let resume = json_resume.into_resume().expect("Failed to convert JSON Resume");
+
+    assert_eq!(resume.info.name, "Jordan Doe");
+    assert_eq!(resume.info.location, "Austin, TX, US");
+    assert_eq!(resume.info.links["website"], "https://jordan.dev");
+    assert_eq!(resume.info.links["github"], "https://github.com/jordan");
+
+    let skill_ids: Vec<&str> = resume.skills.iter().map(|s| s.id.as_str()).collect();
+    assert_eq!(skill_ids, vec!["rust", "postgresql", "kubernetes", "elasticsearch"]);
+    assert_eq!(resume.skills[0].category, "backend");
+    assert_eq!(resume.skills[2].category, "devops");
+    assert_eq!(resume.skills[0].proficiency, Some(Proficiency::Expert));
+    assert_eq!(resume.skills[2].proficiency, None);
+
+    assert_eq!(resume.experiences.len(), 2);
+    let acme = &resume.experiences[0];
+    assert_eq!(acme.id, "acme-corp-backend-engineer");
+    assert_eq!(acme.employer, "Acme Corp");
+    assert_eq!(acme.start_date.to_rfc3339(), "2019-03-01T00:00:00+00:00");
+    assert_eq!(acme.end_date.unwrap().to_rfc3339(), "2021-06-15T00:00:00+00:00");
+    assert_eq!(acme.projects, vec!["acme-corp-backend-engineer"]);
+
+    let globex = &resume.experiences[1];
+    assert!(globex.end_date.is_none());
+    assert_eq!(globex.projects, vec!["search-platform"]);
+
+    let search = resume.projects.iter().find(|p| p.id == "search-platform").unwrap();
+    assert_eq!(search.skills, vec!["rust", "elasticsearch"]);
+
+    let billing = resume.projects.iter().find(|p| p.id == "acme-corp-backend-engineer").unwrap();
+    assert_eq!(billing.title, "Backend Engineer");
+    assert!(billing.description.contains("- Moved deployments to Kubernetes"));
+    assert_eq!(billing.skills, vec!["rust", "kubernetes"]);
+
+    assert_eq!(resume.education.len(), 1);
+    let degree = &resume.education[0];
+    assert_eq!(degree.id, "state-university-bachelor");
+    assert_eq!(degree.degree, "Bachelor");
+    assert_eq!(degree.field.as_deref(), Some("Computer Science"));
+    assert_eq!(degree.grade.as_deref(), Some("3.8"));
+    assert_eq!(degree.end_date.unwrap().to_rfc3339(), "2018-06-30T00:00:00+00:00");
+    assert_eq!(degree.skills, vec!["postgresql"]);
+
+    assert_eq!(resume.certifications.len(), 1);
+    let cka = &resume.certifications[0];
+    assert_eq!(cka.id, "certified-kubernetes-administrator");
+    assert_eq!(cka.issuer, "CNCF");
+    assert_eq!(cka.issued_date.to_rfc3339(), "2022-05-10T00:00:00+00:00");
+    assert_eq!(cka.skills, vec!["kubernetes"]);
+
+    assert_eq!(resume.publications.len(), 1);
+    let talk = &resume.publications[0];
+    assert_eq!(talk.id, "scaling-postgresql-at-acme");
+    assert_eq!(talk.kind, PublicationKind::Paper);
+    assert_eq!(talk.venue.as_deref(), Some("PGConf"));
+    assert_eq!(talk.skills, vec!["postgresql"]);
+
+    let again: JsonResume = serde_json::from_str(JSON_RESUME).unwrap();
+    let again = again.into_resume().unwrap();
+    assert_eq!(
+        resume.projects.iter().map(|p| &p.id).collect::<Vec<_>>(),
+        again.projects.iter().map(|p| &p.id).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_json_resume_partial_end_dates() {
+    let json = JSON_RESUME
+        .replace("\"endDate\": \"2021-06-15\"", "\"endDate\": \"2021\"")
+        .replace("\"startDate\": \"2021-07-01\"", "\"startDate\": \"2021-06\"");
+    let json_resume: JsonResume = serde_json::from_str(&json).unwrap();
+    let resume = json_resume.into_resume().expect("Failed to convert JSON Resume");
+
+    let acme = &resume.experiences[0];
+    assert_eq!(acme.start_date.to_rfc3339(), "2019-03-01T00:00:00+00:00");
+    assert_eq!(acme.end_date.unwrap().to_rfc3339(), "2021-12-31T00:00:00+00:00");
+    assert_eq!(resume.experiences[1].start_date.to_rfc3339(), "2021-06-01T00:00:00+00:00");
+
+    let now = DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z").unwrap().with_timezone(&Utc);
+    assert!(career_gaps(&resume, now, 30).gaps.is_empty());
+
+    let json = JSON_RESUME.replace("\"endDate\": \"2018-06\"", "\"endDate\": \"2024-02\"");
+    let json_resume: JsonResume = serde_json::from_str(&json).unwrap();
+    let resume = json_resume.into_resume().unwrap();
+    assert_eq!(
+        resume.education[0].end_date.unwrap().to_rfc3339(),
+        "2024-02-29T00:00:00+00:00"
+    );
+}
+
+#[test]
+fn test_json_resume_detection_and_errors() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let path = temp_dir.path().join("resume.json");
+    fs::write(&path, JSON_RESUME).unwrap();
+
+    let config = load_config(&path).expect("Failed to load JSON Resume");
+    assert_eq!(config.resume.info.name, "Jordan Doe");
+    assert!(!validate(&config.resume).has_errors());
+
+    let output_path = temp_dir.path().join("dist").to_str().unwrap().to_string();
+    StaticGenerator::new(config.resume, output_path.clone())
+        .generate()
+        .expect("Failed to generate from JSON Resume");
+    assert!(fs::metadata(format!("{}/tools/get_projects_using_skill/rust.json", output_path)).is_ok());
+
+    let invalid = JSON_RESUME.replace("\"2019-03\"", "\"March 2019\"");
+    fs::write(&path, invalid).unwrap();
+    let err = load_json_resume(&path).expect_err("Invalid date should fail");
+    match &err {
+        Error::Convert { field, .. } => assert_eq!(field, "work[0].startDate"),
+        other => panic!("Expected conversion error, got {:?}", other),
+    }
+    assert_eq!(err.exit_code(), 3);
+}
+
+#[test]
+fn test_json_resume_export() {
+    let resume = create_test_resume();
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let output_path = temp_dir.path().to_str().unwrap().to_string();
+
+    let generator = StaticGenerator::new(resume, output_path.clone());
+    generator.generate().expect("Failed to generate static site");
+
+    let content = fs::read_to_string(format!("{}/resume.json", output_path))
+        .expect("Failed to read resume.json");
+    let exported: serde_json::Value = serde_json::from_str(&content)
+        .expect("Failed to parse resume.json");
+
+    assert_eq!(exported["basics"]["name"], "Test User");
+    assert_eq!(exported["basics"]["email"], "test@example.com");
+    assert_eq!(exported["basics"]["location"]["city"], "San Francisco");
+    assert_eq!(exported["basics"]["location"]["region"], "CA");
+    assert_eq!(exported["basics"]["profiles"][0]["network"], "github");
+
+    assert_eq!(exported["work"][0]["name"], "Tech Corp");
+    assert_eq!(exported["work"][0]["position"], "Senior Software Engineer");
+    assert_eq!(exported["work"][0]["startDate"], "2022-01-01");
+    assert!(exported["work"][0].get("endDate").is_none());
+    assert_eq!(exported["work"][1]["endDate"], "2021-12-31");
+
+    assert_eq!(exported["projects"][0]["name"], "E-commerce Platform");
+    assert_eq!(exported["projects"][0]["entity"], "Tech Corp");
+    assert_eq!(
+        exported["projects"][0]["keywords"],
+        serde_json::json!(["Rust", "PostgreSQL", "Docker"])
+    );
+
+    assert_eq!(exported["skills"][0]["name"], "backend");
+    assert_eq!(
+        exported["skills"][0]["keywords"],
+        serde_json::json!(["Rust", "Python", "PostgreSQL", "Apache Kafka", "Redis"])
+    );
+    assert_eq!(exported["skills"][1]["name"], "devops");
+
+    let resource_content = fs::read_to_string(format!("{}/resources/jsonresume.json", output_path))
+        .expect("Failed to read jsonresume resource");
+    let resource: MCPResourceContent = serde_json::from_str(&resource_content)
+        .expect("Failed to parse jsonresume resource");
+    assert_eq!(resource.uri, "resume://jsonresume");
+    assert_eq!(resource.text, content);
+
+    let reimported: JsonResume = serde_json::from_str(&content).unwrap();
+    let reimported = reimported.into_resume().expect("Exported resume should import again");
+    assert_eq!(reimported.experiences.len(), 2);
+    assert_eq!(reimported.experiences[0].projects.len(), 2);
+}
+
+#[test]
+fn test_load_yaml_config() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let path = temp_dir.path().join("config.yaml");
+    fs::write(
+        &path,
+        r#"resume:
+  info:
+    name: Yaml User
+    location: Berlin
+    phone_number: "+49 555 0100"
+    email: yaml@example.com
+    links:
+      github: https://github.com/yaml
+  experiences:
+    - id: exp1
+      title: Engineer
+      employer: Yaml GmbH
+      start_date: 2020-01-01T00:00:00Z
+      end_date: 2022-06-30
+      projects: [proj1]
+  projects:
+    - id: proj1
+      title: Config Loader
+      duration: 3 months
+      description: |
+        First paragraph.
+
+        Second paragraph with `code`.
+      skills: [rust]
+  skills:
+    - id: rust
+      name: Rust
+      type: programming_language
+      category: backend
+"#,
+    )
+    .unwrap();
+
+    let config = load_config(&path).expect("Failed to load YAML config");
+    assert_eq!(config.resume.info.name, "Yaml User");
+    assert_eq!(
+        config.resume.projects[0].description,
+        "First paragraph.\n\nSecond paragraph with `code`.\n"
+    );
+    assert_eq!(
+        config.resume.experiences[0].end_date.unwrap().to_rfc3339(),
+        "2022-06-30T00:00:00+00:00"
+    );
+
+    fs::write(&path, "resume:\n  info:\n    name: [unterminated\n").unwrap();
+    match load_config(&path).expect_err("Broken YAML should fail") {
+        Error::Parse { line, column, .. } => {
+            assert_eq!(line, 4);
+            assert!(column > 0);
+        }
+        other => panic!("Expected parse error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_load_toml_config() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let path = temp_dir.path().join("config.toml");
+    fs::write(
+        &path,
+        r#"[resume.info]
+name = "Toml User"
+location = "Oslo"
+phone_number = "+47 555 0100"
+email = "toml@example.com"
+links = { github = "https://github.com/toml" }
+
+[[resume.experiences]]
+id = "exp1"
+title = "Engineer"
+employer = "Toml AS"
+start_date = 2019-05-01T00:00:00Z
+projects = ["proj1"]
+
+[[resume.experiences]]
+id = "exp2"
+title = "Senior Engineer"
+employer = "Toml AS"
+start_date = 2021-01-04T09:00:00
+end_date = 2022-03-15T17:30:00.5
+projects = []
+
+[[resume.projects]]
+id = "proj1"
+title = "Parser"
+description = """
+Line one.
+Line two."""
+skills = ["rust"]
+
+[[resume.skills]]
+id = "rust"
+name = "Rust"
+type = "programming_language"
+category = "backend"
+"#,
+    )
+    .unwrap();
+
+    let config = load_config(&path).expect("Failed to load TOML config");
+    assert_eq!(config.resume.info.name, "Toml User");
+    assert_eq!(config.resume.projects[0].description, "Line one.\nLine two.");
+    assert!(config.resume.projects[0].duration.is_none());
+    assert!(config.resume.experiences[0].end_date.is_none());
+    assert_eq!(
+        config.resume.experiences[0].start_date.to_rfc3339(),
+        "2019-05-01T00:00:00+00:00"
+    );
+    assert_eq!(
+        config.resume.experiences[1].start_date.to_rfc3339(),
+        "2021-01-04T09:00:00+00:00"
+    );
+    assert_eq!(
+        config.resume.experiences[1].end_date.unwrap().to_rfc3339(),
+        "2022-03-15T17:30:00.500+00:00"
+    );
+
+    fs::write(&path, "[resume.info]\nname = \"Toml User\"\nlocation = 42\n").unwrap();
+    match load_config(&path).expect_err("Invalid TOML should fail") {
+        Error::Parse { line, column, message, .. } => {
+            assert_eq!((line, column), (3, 12));
+            assert!(message.contains("invalid type"), "Unexpected message: {}", message);
+        }
+        other => panic!("Expected parse error, got {:?}", other),
+    }
+}
+
+fn write_split_config(dir: &std::path::Path) {
+    let resume = create_test_resume();
+    fs::create_dir_all(dir.join("projects")).unwrap();
+    fs::create_dir_all(dir.join("skills")).unwrap();
+    fs::write(
+        dir.join("config.json"),
+        serde_json::to_string_pretty(&serde_json::json!({
+            "resume": {
+                "info": resume.info,
+                "experiences": resume.experiences,
+            },
+            "include": ["projects/*.json", "skills/*.yaml"]
+        }))
+        .unwrap(),
+    )
+    .unwrap();
+    for project in &resume.projects {
+        fs::write(
+            dir.join(format!("projects/{}.json", project.id)),
+            serde_json::to_string(&serde_json::json!({ "projects": [project] })).unwrap(),
+        )
+        .unwrap();
+    }
+    let (backend, rest): (Vec<Skill>, Vec<Skill>) = resume
+        .skills
+        .into_iter()
+        .partition(|s| s.category == "backend");
+    fs::write(
+        dir.join("skills/backend.yaml"),
+        serde_yaml::to_string(&serde_json::json!({ "skills": backend })).unwrap(),
+    )
+    .unwrap();
+    fs::write(
+        dir.join("skills/devops.yaml"),
+        serde_yaml::to_string(&serde_json::json!({ "skills": rest })).unwrap(),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_config_includes() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    write_split_config(temp_dir.path());
+
+    let config = load_config(temp_dir.path().join("config.json")).expect("Failed to load split config");
+    assert_eq!(config.resume.experiences.len(), 2);
+    assert_eq!(
+        config.resume.projects.iter().map(|p| p.id.as_str()).collect::<Vec<_>>(),
+        vec!["proj1", "proj2", "proj3"]
+    );
+    assert_eq!(config.resume.skills.len(), 6);
+    assert!(!validate(&config.resume).has_errors());
+
+    let mut overlapping: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(temp_dir.path().join("config.json")).unwrap()).unwrap();
+    overlapping["include"] = serde_json::json!(["projects/*.json", "./projects/proj1.json", "skills/*.yaml"]);
+    fs::write(temp_dir.path().join("overlapping.json"), overlapping.to_string()).unwrap();
+    let config = load_config(temp_dir.path().join("overlapping.json")).expect("Overlapping patterns should load");
+    assert_eq!(config.resume.projects.len(), 3);
+    assert!(!validate(&config.resume).has_errors());
+
+    fs::write(
+        temp_dir.path().join("projects/copy.json"),
+        r#"{ "projects": [{ "id": "proj2", "title": "Copy", "duration": null, "description": "", "skills": [] }] }"#,
+    )
+    .unwrap();
+    let err = load_config(temp_dir.path().join("config.json")).expect_err("Duplicate ids should fail");
+    match &err {
+        Error::DuplicateId { kind, id, first, second } => {
+            assert_eq!(*kind, "project");
+            assert_eq!(id, "proj2");
+            assert!(first.ends_with("projects/copy.json"));
+            assert!(second.ends_with("projects/proj2.json"));
+        }
+        other => panic!("Expected duplicate id error, got {:?}", other),
+    }
+    assert!(err.to_string().contains("copy.json"));
+    assert_eq!(err.exit_code(), 4);
+}
+
+#[test]
+fn test_include_fragment_shape() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    write_split_config(temp_dir.path());
+    let config_path = temp_dir.path().join("config.json");
+    let extra = temp_dir.path().join("projects/p4.json");
+
+    fs::write(
+        &extra,
+        r#"{ "id": "p4", "title": "Bare", "duration": null, "description": "", "skills": [] }"#,
+    )
+    .unwrap();
+    match load_config(&config_path).expect_err("A bare project should fail") {
+        Error::Parse { path, message, .. } => {
+            assert_eq!(path, extra);
+            assert!(message.contains("unknown field `id`"), "Unexpected message: {}", message);
+        }
+        other => panic!("Expected parse error, got {:?}", other),
+    }
+
+    fs::write(
+        &extra,
+        r#"{ "projects": [{ "id": "p4", "title": "Typo", "duration": null, "description": "", "skills": [] }], "skils": [] }"#,
+    )
+    .unwrap();
+    match load_config(&config_path).expect_err("A misspelled section should fail") {
+        Error::Parse { message, .. } => {
+            assert!(message.contains("unknown field `skils`"), "Unexpected message: {}", message)
+        }
+        other => panic!("Expected parse error, got {:?}", other),
+    }
+
+    fs::write(&extra, r#"{ "projects": [] }"#).unwrap();
+    let err = load_config(&config_path).expect_err("An empty fragment should fail");
+    match &err {
+        Error::Include { path, pattern, message } => {
+            assert_eq!(path, &config_path);
+            assert_eq!(pattern, "projects/*.json");
+            assert!(message.contains("p4.json"), "Unexpected message: {}", message);
+        }
+        other => panic!("Expected include error, got {:?}", other),
+    }
+    assert_eq!(err.exit_code(), 2);
+}
+
+#[test]
+fn test_validation_names_source_files() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    write_split_config(temp_dir.path());
+    let proj2 = temp_dir.path().join("projects/proj2.json");
+    fs::write(
+        &proj2,
+        r#"{ "projects": [{ "id": "proj2", "title": "Data", "duration": null, "description": "", "skills": ["ghost"] }] }"#,
+    )
+    .unwrap();
+
+    let config = load_config(temp_dir.path().join("config.json")).expect("Failed to load split config");
+    assert_eq!(config.origins.get("project", "proj2"), Some(proj2.as_path()));
+    assert_eq!(
+        config.origins.get("experience", "exp1"),
+        Some(temp_dir.path().join("config.json").as_path())
+    );
+    assert!(config.origins.get("project", "missing").is_none());
+
+    let report = validate_with_origins(&config.resume, &config.origins);
+    let dangling: Vec<&ValidationIssue> = report.errors().collect();
+    assert_eq!(dangling.len(), 1);
+    assert_eq!(dangling[0].kind, IssueKind::DanglingReference);
+    assert_eq!(dangling[0].origin.as_deref(), Some(proj2.as_path()));
+    assert!(validate(&config.resume).errors().all(|issue| issue.origin.is_none()));
+
+    let output_path = temp_dir.path().join("dist").to_str().unwrap().to_string();
+    let err = StaticGenerator::new(config.resume, output_path)
+        .with_origins(config.origins)
+        .generate()
+        .expect_err("A dangling reference should fail");
+    let message = err.to_string();
+    assert!(
+        message.contains(&format!("{}: resume.projects[1].skills[0]", proj2.display())),
+        "Unexpected message: {}",
+        message
+    );
+}
+
+#[test]
+fn test_config_directory() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let dir = temp_dir.path();
+    let resume = create_test_resume();
+    fs::create_dir_all(dir.join("experiences")).unwrap();
+    fs::create_dir_all(dir.join("projects")).unwrap();
+    fs::create_dir_all(dir.join("skills")).unwrap();
+    fs::write(
+        dir.join("config.yaml"),
+        serde_yaml::to_string(&serde_json::json!({ "resume": { "info": resume.info } })).unwrap(),
+    )
+    .unwrap();
+    for experience in &resume.experiences {
+        fs::write(
+            dir.join(format!("experiences/{}.json", experience.id)),
+            serde_json::to_string(experience).unwrap(),
+        )
+        .unwrap();
+    }
+    for project in &resume.projects {
+        fs::write(
+            dir.join(format!("projects/{}.yaml", project.id)),
+            serde_yaml::to_string(project).unwrap(),
+        )
+        .unwrap();
+    }
+    for skill in &resume.skills {
+        fs::write(
+            dir.join(format!("skills/{}.json", skill.id)),
+            serde_json::to_string(skill).unwrap(),
+        )
+        .unwrap();
+    }
+    fs::write(dir.join("skills/README.txt"), "not a skill").unwrap();
+
+    let config = load_config(dir).expect("Failed to load config directory");
+    assert_eq!(config.resume.info.name, "Test User");
+    assert_eq!(config.resume.experiences.len(), 2);
+    assert_eq!(config.resume.projects.len(), 3);
+    assert_eq!(config.resume.skills.len(), 6);
+    assert!(!validate(&config.resume).has_errors());
+
+    fs::write(dir.join("projects/broken.json"), "{ \"id\": \"broken\" }").unwrap();
+    match load_config(dir).expect_err("Invalid project file should fail") {
+        Error::Parse { path, .. } => assert!(path.ends_with("projects/broken.json")),
+        other => panic!("Expected parse error, got {:?}", other),
+    }
+}
+
+const MARKDOWN_PROJECT: &str = "---
+id: proj4
+title: Static Site Generator
+duration: 2 months
+skills: [rust, docker]
+---
+
+# Overview
+
+Generates **static** MCP sites.
+
+- Fast
+- Cacheable
+";
+
+#[test]
+fn test_markdown_project_parsing() {
+    let path = std::path::Path::new("projects/generator.md");
+    let project = parse_markdown_project(path, MARKDOWN_PROJECT).expect("Failed to parse Markdown project");
+    assert_eq!(project.id, "proj4");
+    assert_eq!(project.title, "Static Site Generator");
+    assert_eq!(project.duration.as_deref(), Some("2 months"));
+    assert_eq!(project.skills, vec!["rust", "docker"]);
+    assert_eq!(
+        project.description,
+        "# Overview\n\nGenerates **static** MCP sites.\n\n- Fast\n- Cacheable"
+    );
+
+    let without_id = MARKDOWN_PROJECT.replace("id: proj4\n", "");
+    let project = parse_markdown_project(path, &without_id).unwrap();
+    assert_eq!(project.id, "generator");
+
+    let toml_front_matter = "+++\ntitle = \"Toml Project\"\nskills = [\"rust\"]\n+++\nBody text\n";
+    let project = parse_markdown_project(path, toml_front_matter).unwrap();
+    assert_eq!(project.title, "Toml Project");
+    assert_eq!(project.description, "Body text");
+
+    let broken = "---\nid: proj4\ntitle: [oops\n---\nBody\n";
+    match parse_markdown_project(path, broken).expect_err("Broken front matter should fail") {
+        Error::Parse { line, .. } => assert_eq!(line, 3),
+        other => panic!("Expected parse error, got {:?}", other),
+    }
+    assert!(parse_markdown_project(path, "No front matter").is_err());
+    assert!(parse_markdown_project(path, "---\ntitle: Unclosed\n").is_err());
+}
+
+#[test]
+fn test_markdown_projects_are_published() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    write_split_config(temp_dir.path());
+    fs::write(temp_dir.path().join("projects/generator.md"), MARKDOWN_PROJECT).unwrap();
+    let mut base: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(temp_dir.path().join("config.json")).unwrap()).unwrap();
+    base["include"]
+        .as_array_mut()
+        .unwrap()
+        .push(serde_json::json!("projects/*.md"));
+    base["resume"]["experiences"][0]["projects"]
+        .as_array_mut()
+        .unwrap()
+        .push(serde_json::json!("proj4"));
+    fs::write(temp_dir.path().join("config.json"), base.to_string()).unwrap();
+
+    let config = load_config(temp_dir.path().join("config.json")).expect("Failed to load config");
+    assert_eq!(config.resume.projects.len(), 4);
+    assert_eq!(config.project_markdown["proj4"], MARKDOWN_PROJECT);
+
+    let output_path = temp_dir.path().join("dist").to_str().unwrap().to_string();
+    let generator = StaticGenerator::new(config.resume, output_path.clone())
+        .with_project_markdown(config.project_markdown);
+    generator.generate().expect("Failed to generate static site");
+
+    let content = fs::read_to_string(format!("{}/resources/markdown/proj4.json", output_path))
+        .expect("Failed to read Markdown resource");
+    let resource: MCPResourceContent = serde_json::from_str(&content).unwrap();
+    assert_eq!(resource.uri, "resume://markdown/proj4");
+    assert_eq!(resource.mime_type, "text/markdown");
+    assert_eq!(resource.text, MARKDOWN_PROJECT);
+
+    let manifest: MCPManifest =
+        serde_json::from_str(&fs::read_to_string(format!("{}/mcp.json", output_path)).unwrap()).unwrap();
+    let markdown_resource = manifest
+        .capabilities
+        .resources
+        .iter()
+        .find(|r| r.uri == "resume://markdown/proj4")
+        .expect("Markdown resource should be listed");
+    assert_eq!(markdown_resource.mime_type, "text/markdown");
+
+    let project_details = fs::read_to_string(format!("{}/tools/get_project_details/proj4.json", output_path)).unwrap();
+    assert!(project_details.contains("Generates **static** MCP sites."));
+}
+
+fn create_test_education() -> Education {
+    Education {
+        id: "bsc".to_string(),
+        institution: "State University".to_string(),
+        degree: "BSc".to_string(),
+        field: Some("Computer Science".to_string()),
+        start_date: Some(
+            DateTime::parse_from_rfc3339("2015-09-01T00:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        ),
+        end_date: Some(
+            DateTime::parse_from_rfc3339("2019-06-30T00:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        ),
+        grade: Some("First Class".to_string()),
+        projects: vec!["proj3".to_string()],
+        skills: vec!["rust".to_string()],
+    }
+}
+
+#[test]
+fn test_education_index_and_tools() {
+    let mut resume = create_test_resume();
+    resume.education.push(create_test_education());
+
+    let index = build_index(&resume);
+    assert_eq!(index.skill_to_education["rust"], vec!["bsc"]);
+    assert_eq!(index.skill_to_education["python"], vec!["bsc"]);
+    assert!(!index.skill_to_education.contains_key("kafka"));
+    assert!(index.education_lookup.contains_key("bsc"));
+
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let output_path = temp_dir.path().to_str().unwrap().to_string();
+    let generator = StaticGenerator::new(resume, output_path.clone());
+    generator.generate().expect("Failed to generate static site");
+
+    let resource: MCPResourceContent = serde_json::from_str(
+        &fs::read_to_string(format!("{}/resources/education.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(resource.uri, "resume://education");
+    let education: Vec<Education> = serde_json::from_str(&resource.text).unwrap();
+    assert_eq!(education[0].institution, "State University");
+
+    let details: MCPToolResult = serde_json::from_str(
+        &fs::read_to_string(format!("{}/tools/get_education_details/bsc.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    let details: Education = serde_json::from_str(&details.content[0].text).unwrap();
+    assert_eq!(details.degree, "BSc");
+    assert_eq!(details.grade.as_deref(), Some("First Class"));
+
+    let using_docker: MCPToolResult = serde_json::from_str(
+        &fs::read_to_string(format!("{}/tools/get_education_using_skill/docker.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    let using_docker: Vec<Education> = serde_json::from_str(&using_docker.content[0].text).unwrap();
+    assert_eq!(using_docker.len(), 1);
+
+    let using_kafka: MCPToolResult = serde_json::from_str(
+        &fs::read_to_string(format!("{}/tools/get_education_using_skill/kafka.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    let using_kafka: Vec<Education> = serde_json::from_str(&using_kafka.content[0].text).unwrap();
+    assert!(using_kafka.is_empty());
+
+    let index_file: HashMap<String, Vec<String>> = serde_json::from_str(
+        &fs::read_to_string(format!("{}/indexes/skill_to_education.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(index_file["postgresql"], vec!["bsc"]);
+}
+
+#[test]
+fn test_education_validation() {
+    let mut resume = create_test_resume();
+    let mut education = create_test_education();
+    education.skills.push("latin".to_string());
+    education.projects.push("thesis".to_string());
+    std::mem::swap(&mut education.start_date, &mut education.end_date);
+    resume.education.push(education);
+
+    let report = validate(&resume);
+    let paths: Vec<&str> = report.errors().map(|i| i.path.as_str()).collect();
+    assert_eq!(
+        paths,
+        vec![
+            "resume.education[0].projects[1]",
+            "resume.education[0].skills[1]",
+            "resume.education[0].end_date",
+        ]
+    );
+}
+
+fn create_test_certifications() -> Vec<Certification> {
+    let date = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc);
+    vec![
+        Certification {
+            id: "cka".to_string(),
+            name: "Certified Kubernetes Administrator".to_string(),
+            issuer: "CNCF".to_string(),
+            issued_date: date("2022-05-10T00:00:00Z"),
+            expiry_date: Some(date("2025-05-10T00:00:00Z")),
+            credential_id: Some("LF-123".to_string()),
+            url: None,
+            skills: vec!["kafka".to_string()],
+        },
+        Certification {
+            id: "aws-sa".to_string(),
+            name: "AWS Solutions Architect".to_string(),
+            issuer: "Amazon Web Services".to_string(),
+            issued_date: date("2023-01-15T00:00:00Z"),
+            expiry_date: Some(date("2026-01-15T00:00:00Z")),
+            credential_id: None,
+            url: Some("https://aws.amazon.com/verification".to_string()),
+            skills: vec!["docker".to_string()],
+        },
+        Certification {
+            id: "oscp".to_string(),
+            name: "Offensive Security Certified Professional".to_string(),
+            issuer: "OffSec".to_string(),
+            issued_date: date("2021-03-01T00:00:00Z"),
+            expiry_date: None,
+            credential_id: None,
+            url: None,
+            skills: vec!["python".to_string()],
+        },
+    ]
+}
+
+#[test]
+fn test_active_certifications() {
+    let mut resume = create_test_resume();
+    resume.certifications = create_test_certifications();
+
+    let index = build_index(&resume);
+    assert_eq!(index.skill_to_certifications["kafka"], vec!["cka"]);
+    assert!(index.certification_lookup.contains_key("oscp"));
+
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let output_path = temp_dir.path().to_str().unwrap().to_string();
+    let generated_at = DateTime::parse_from_rfc3339("2025-06-01T00:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc);
+    StaticGenerator::new(resume, output_path.clone())
+        .with_generated_at(generated_at)
+        .generate()
+        .expect("Failed to generate static site");
+
+    let resource: MCPResourceContent = serde_json::from_str(
+        &fs::read_to_string(format!("{}/resources/certifications.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(resource.uri, "resume://certifications");
+    let certifications: Vec<Certification> = serde_json::from_str(&resource.text).unwrap();
+    assert_eq!(certifications.len(), 3);
+
+    let active: MCPToolResult = serde_json::from_str(
+        &fs::read_to_string(format!("{}/tools/get_active_certifications.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    let active: Vec<Certification> = serde_json::from_str(&active.content[0].text).unwrap();
+    let active_ids: Vec<&str> = active.iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(active_ids, vec!["aws-sa", "oscp"]);
+
+    let index_file: HashMap<String, Vec<String>> = serde_json::from_str(
+        &fs::read_to_string(format!("{}/indexes/skill_to_certifications.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(index_file["docker"], vec!["aws-sa"]);
+}
+
+#[test]
+fn test_certification_validation() {
+    let mut resume = create_test_resume();
+    let mut certifications = create_test_certifications();
+    certifications[0].skills.push("cobol".to_string());
+    certifications[1].expiry_date = Some(
+        DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc),
+    );
+    certifications[2].id = "cka".to_string();
+    resume.certifications = certifications;
+
+    let report = validate(&resume);
+    let paths: Vec<&str> = report.errors().map(|i| i.path.as_str()).collect();
+    assert_eq!(
+        paths,
+        vec![
+            "resume.certifications[2].id",
+            "resume.certifications[0].skills[1]",
+            "resume.certifications[1].expiry_date",
+        ]
+    );
+}
+
+fn create_test_publications() -> Vec<Publication> {
+    let date = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc);
+    vec![
+        Publication {
+            id: "rustconf-talk".to_string(),
+            title: "Zero-copy parsing in Rust".to_string(),
+            kind: PublicationKind::Talk,
+            venue: Some("RustConf".to_string()),
+            date: date("2022-09-12T00:00:00Z"),
+            co_authors: vec![],
+            url: None,
+            skills: vec!["rust".to_string()],
+        },
+        Publication {
+            id: "kafka-paper".to_string(),
+            title: "Exactly-once delivery in practice".to_string(),
+            kind: PublicationKind::Paper,
+            venue: Some("VLDB".to_string()),
+            date: date("2021-08-16T00:00:00Z"),
+            co_authors: vec!["A. Researcher".to_string()],
+            url: Some("https://example.com/paper.pdf".to_string()),
+            skills: vec!["kafka".to_string(), "rust".to_string()],
+        },
+        Publication {
+            id: "meetup-talk".to_string(),
+            title: "Caching with Redis".to_string(),
+            kind: PublicationKind::Talk,
+            venue: None,
+            date: date("2023-03-02T00:00:00Z"),
+            co_authors: vec![],
+            url: None,
+            skills: vec!["redis".to_string(), "rust".to_string()],
+        },
+    ]
+}
+
+#[test]
+fn test_publication_tools() {
+    let mut resume = create_test_resume();
+    resume.publications = create_test_publications();
+
+    let index = build_index(&resume);
+    assert_eq!(
+        index.skill_to_publications["rust"],
+        vec!["kafka-paper", "meetup-talk", "rustconf-talk"]
+    );
+    assert!(index.publication_lookup.contains_key("kafka-paper"));
+
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let output_path = temp_dir.path().to_str().unwrap().to_string();
+    StaticGenerator::new(resume, output_path.clone())
+        .generate()
+        .expect("Failed to generate static site");
+
+    let resource: MCPResourceContent = serde_json::from_str(
+        &fs::read_to_string(format!("{}/resources/publications.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(resource.uri, "resume://publications");
+    assert!(resource.text.contains("\"kind\": \"talk\""));
+
+    let talks: MCPToolResult = serde_json::from_str(
+        &fs::read_to_string(format!("{}/tools/get_publications_by_kind/talk.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    let talks: Vec<Publication> = serde_json::from_str(&talks.content[0].text).unwrap();
+    let talk_ids: Vec<&str> = talks.iter().map(|p| p.id.as_str()).collect();
+    assert_eq!(talk_ids, vec!["meetup-talk", "rustconf-talk"]);
+
+    let patents: MCPToolResult = serde_json::from_str(
+        &fs::read_to_string(format!("{}/tools/get_publications_by_kind/patent.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    let patents: Vec<Publication> = serde_json::from_str(&patents.content[0].text).unwrap();
+    assert!(patents.is_empty());
+
+    let using_kafka: MCPToolResult = serde_json::from_str(
+        &fs::read_to_string(format!("{}/tools/get_publications_using_skill/kafka.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    let using_kafka: Vec<Publication> = serde_json::from_str(&using_kafka.content[0].text).unwrap();
+    assert_eq!(using_kafka.len(), 1);
+    assert_eq!(using_kafka[0].co_authors, vec!["A. Researcher"]);
+
+    let mut invalid = create_test_resume();
+    invalid.publications = create_test_publications();
+    invalid.publications[1].skills.push("fortran".to_string());
+    let report = validate(&invalid);
+    let paths: Vec<&str> = report.errors().map(|i| i.path.as_str()).collect();
+    assert_eq!(paths, vec!["resume.publications[1].skills[2]"]);
+}
+
+#[test]
+fn test_merge_intervals() {
+    let date = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc);
+    let merged = merge_intervals(vec![
+        (date("2021-06-01T00:00:00Z"), date("2022-06-01T00:00:00Z")),
+        (date("2020-01-01T00:00:00Z"), date("2021-12-31T00:00:00Z")),
+        (date("2023-01-01T00:00:00Z"), date("2023-02-01T00:00:00Z")),
+    ]);
+    assert_eq!(
+        merged,
+        vec![
+            (date("2020-01-01T00:00:00Z"), date("2022-06-01T00:00:00Z")),
+            (date("2023-01-01T00:00:00Z"), date("2023-02-01T00:00:00Z")),
+        ]
+    );
+}
+
+#[test]
+fn test_skill_experience_summary() {
+    let mut resume = create_test_resume();
+    resume.skills[0].proficiency = Some(Proficiency::Expert);
+    resume.experiences.push(Experience {
+        id: "exp3".to_string(),
+        title: "Consultant".to_string(),
+        employer: "Freelance".to_string(),
+        start_date: DateTime::parse_from_rfc3339("2021-06-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc),
+        end_date: Some(
+            DateTime::parse_from_rfc3339("2022-06-01T00:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        ),
+        projects: vec!["proj3".to_string()],
+    });
+
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let output_path = temp_dir.path().to_str().unwrap().to_string();
+    let generated_at = DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc);
+    StaticGenerator::new(resume, output_path.clone())
+        .with_generated_at(generated_at)
+        .generate()
+        .expect("Failed to generate static site");
+
+    let read_summary = |skill: &str| -> SkillExperienceSummary {
+        let result: MCPToolResult = serde_json::from_str(
+            &fs::read_to_string(format!(
+                "{}/tools/get_skill_experience_summary/{}.json",
+                output_path, skill
+            ))
+            .unwrap(),
+        )
+        .unwrap();
+        serde_json::from_str(&result.content[0].text).unwrap()
+    };
+
+    let rust = read_summary("rust");
+    assert_eq!(rust.skill.proficiency, Some(Proficiency::Expert));
+    assert_eq!(rust.experiences, vec!["exp1"]);
+    assert_eq!(rust.years_of_experience, 2.0);
+
+    // exp3 overlaps exp1 and exp2, so it adds nothing to the merged span.
+    let docker = read_summary("docker");
+    assert_eq!(docker.experiences, vec!["exp1", "exp2", "exp3"]);
+    assert_eq!(docker.years_of_experience, 4.0);
+
+    let python = read_summary("python");
+    assert_eq!(python.skill.proficiency, None);
+    assert_eq!(python.years_of_experience, 2.4);
+    assert_eq!(
+        python.last_used,
+        Some(LastUsed::Date(
+            DateTime::parse_from_rfc3339("2022-06-01T00:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc)
+        ))
+    );
+    assert_eq!(rust.last_used, Some(LastUsed::Present));
+
+    let recency: MCPToolResult = serde_json::from_str(
+        &fs::read_to_string(format!("{}/tools/get_skills_by_recency.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    assert!(recency.content[0].text.contains("\"last_used\": \"present\""));
+    let recency: Vec<SkillExperienceSummary> = serde_json::from_str(&recency.content[0].text).unwrap();
+    let recency_ids: Vec<&str> = recency.iter().map(|s| s.skill.id.as_str()).collect();
+    assert_eq!(
+        recency_ids,
+        vec!["docker", "postgresql", "kafka", "redis", "rust", "python"]
+    );
+
+    let resource: MCPResourceContent = serde_json::from_str(
+        &fs::read_to_string(format!("{}/resources/skills.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    let skills: Vec<SkillExperienceSummary> = serde_json::from_str(&resource.text).unwrap();
+    assert_eq!(skills.len(), 6);
+    assert_eq!(skills[0].skill.id, "rust");
+    assert_eq!(skills[0].years_of_experience, 2.0);
+    assert!(resource.text.contains("\"proficiency\": \"expert\""));
+}
+
+#[test]
+fn test_career_timeline() {
+    let mut resume = create_test_resume();
+    resume.experiences.push(Experience {
+        id: "exp0".to_string(),
+        title: "Intern".to_string(),
+        employer: "Tech Corp".to_string(),
+        start_date: DateTime::parse_from_rfc3339("2018-06-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc),
+        end_date: Some(
+            DateTime::parse_from_rfc3339("2018-09-01T00:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        ),
+        projects: vec![],
+    });
+    let now = Utc::now();
+    for year in [300_000, i32::MAX, i32::MIN] {
+        assert!(experiences_active_in_year(&resume, year, now).is_empty());
+    }
+
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let output_path = temp_dir.path().to_str().unwrap().to_string();
+    let generated_at = DateTime::parse_from_rfc3339("2024-03-01T00:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc);
+    StaticGenerator::new(resume, output_path.clone())
+        .with_generated_at(generated_at)
+        .generate()
+        .expect("Failed to generate static site");
+
+    let read_year = |year: i32| -> Vec<String> {
+        let result: MCPToolResult = serde_json::from_str(
+            &fs::read_to_string(format!(
+                "{}/tools/get_experiences_active_in_year/{}.json",
+                output_path, year
+            ))
+            .unwrap(),
+        )
+        .unwrap();
+        let experiences: Vec<Experience> = serde_json::from_str(&result.content[0].text).unwrap();
+        experiences.into_iter().map(|e| e.id).collect()
+    };
+    assert_eq!(read_year(2018), vec!["exp0"]);
+    assert!(read_year(2019).is_empty());
+    assert_eq!(read_year(2021), vec!["exp2"]);
+    assert_eq!(read_year(2022), vec!["exp1"]);
+    assert_eq!(read_year(2024), vec!["exp1"]);
+    assert!(fs::metadata(format!("{}/tools/get_experiences_active_in_year/2017.json", output_path)).is_err());
+    assert!(fs::metadata(format!("{}/tools/get_experiences_active_in_year/2025.json", output_path)).is_err());
+
+    let result: MCPToolResult = serde_json::from_str(
+        &fs::read_to_string(format!("{}/tools/get_career_timeline.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    let timeline: Vec<TimelineEntry> = serde_json::from_str(&result.content[0].text).unwrap();
+    assert_eq!(timeline.len(), 4);
+    assert!(matches!(&timeline[0], TimelineEntry::Experience { id, .. } if id == "exp0"));
+    match &timeline[1] {
+        TimelineEntry::Gap { start_date, end_date, days } => {
+            assert_eq!(start_date.to_rfc3339(), "2018-09-01T00:00:00+00:00");
+            assert_eq!(end_date.to_rfc3339(), "2020-01-01T00:00:00+00:00");
+            assert_eq!(*days, 487);
+        }
+        other => panic!("Expected a gap, got {:?}", other),
+    }
+    assert!(matches!(&timeline[2], TimelineEntry::Experience { id, .. } if id == "exp2"));
+    assert!(matches!(&timeline[3], TimelineEntry::Experience { id, .. } if id == "exp1"));
+    assert!(result.content[0].text.contains("\"type\": \"gap\""));
+}
+
+#[test]
+fn test_career_gaps_and_overlaps() {
+    let date = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc);
+    let mut resume = create_test_resume();
+    resume.experiences.push(Experience {
+        id: "consulting".to_string(),
+        title: "Consultant".to_string(),
+        employer: "Freelance".to_string(),
+        start_date: date("2021-10-01T00:00:00Z"),
+        end_date: Some(date("2022-03-01T00:00:00Z")),
+        projects: vec![],
+    });
+    resume.experiences.push(Experience {
+        id: "intern".to_string(),
+        title: "Intern".to_string(),
+        employer: "Tech Corp".to_string(),
+        start_date: date("2019-06-01T00:00:00Z"),
+        end_date: Some(date("2019-11-01T00:00:00Z")),
+        projects: vec![],
+    });
+    let now = date("2024-01-01T00:00:00Z");
+
+    let career = career_gaps(&resume, now, 30);
+    assert_eq!(career.threshold_days, 30);
+    assert_eq!(career.gaps.len(), 1);
+    assert_eq!(career.gaps[0].after, "intern");
+    assert_eq!(career.gaps[0].before, "exp2");
+    assert_eq!(career.gaps[0].days, 61);
+
+    let overlapping: Vec<&[String; 2]> = career.overlaps.iter().map(|o| &o.experiences).collect();
+    assert_eq!(
+        overlapping,
+        vec![
+            &["exp2".to_string(), "consulting".to_string()],
+            &["consulting".to_string(), "exp1".to_string()],
+        ]
+    );
+    assert_eq!(career.overlaps[1].days, 59);
+
+    assert!(career_gaps(&resume, now, 90).gaps.is_empty());
+
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let output_path = temp_dir.path().to_str().unwrap().to_string();
+    StaticGenerator::new(resume, output_path.clone())
+        .with_generated_at(now)
+        .with_options(GeneratorOptions {
+            gap_threshold_days: 30,
+            ..GeneratorOptions::default()
+        })
+        .generate()
+        .expect("Failed to generate static site");
+
+    let result: MCPToolResult = serde_json::from_str(
+        &fs::read_to_string(format!("{}/tools/get_career_gaps.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    let written: CareerGaps = serde_json::from_str(&result.content[0].text).unwrap();
+    assert_eq!(written, career);
+}
+
+#[test]
+fn test_skills_by_recency_puts_unused_skills_last() {
+    let mut resume = create_test_resume();
+    resume.projects[1].skills.retain(|s| s != "redis");
+    let index = build_index(&resume);
+    let now = DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc);
+
+    let ranked = skills_by_recency(skill_experience_summaries(&resume, &index, now));
+    let last = ranked.last().unwrap();
+    assert_eq!(last.skill.id, "redis");
+    assert_eq!(last.last_used, None);
+    assert!(ranked[0].last_used > Some(LastUsed::Date(now)));
+}
+
+#[test]
+fn test_search_index() {
+    assert_eq!(
+        tokenize("Built a Real-time pipeline, with Kafka!"),
+        vec!["built", "real", "time", "pipeline", "kafka"]
+    );
+
+    let mut resume = create_test_resume();
+    resume.projects[1].description = "Real-time data pipeline built on Apache Kafka".to_string();
+    let index = build_search_index(&resume);
+
+    let kafka = index.search("Kafka").expect("kafka should be indexed");
+    assert_eq!(kafka.term, "kafka");
+    assert_eq!(kafka.projects.len(), 1);
+    assert_eq!(kafka.projects[0].id, "proj2");
+    assert_eq!(kafka.projects[0].title, "Data Pipeline");
+    assert_eq!(kafka.projects[0].fields, vec!["description"]);
+    assert_eq!(kafka.skills[0].id, "kafka");
+    assert_eq!(kafka.skills[0].title, "Apache Kafka");
+    assert!(kafka.experiences.is_empty());
+
+    let engineer = index.search("engineer").unwrap();
+    let ids: Vec<&str> = engineer.experiences.iter().map(|h| h.id.as_str()).collect();
+    assert_eq!(ids, vec!["exp1", "exp2"]);
+
+    // Title matches outrank description matches.
+    let pipeline = index.search("pipeline").unwrap();
+    assert_eq!(pipeline.projects[0].id, "proj2");
+    assert_eq!(pipeline.projects[0].score, 4);
+    assert_eq!(pipeline.projects[0].fields, vec!["title", "description"]);
+
+    assert!(index.search("the").is_none());
+    assert!(index.search("nonexistent").is_none());
+
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let output_path = temp_dir.path().to_str().unwrap().to_string();
+    StaticGenerator::new(resume, output_path.clone())
+        .generate()
+        .expect("Failed to generate static site");
+
+    let result: MCPToolResult = serde_json::from_str(
+        &fs::read_to_string(format!("{}/tools/search_resume/startupco.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    let results: SearchResults = serde_json::from_str(&result.content[0].text).unwrap();
+    assert_eq!(results.experiences[0].id, "exp2");
+    assert_eq!(results.experiences[0].fields, vec!["employer"]);
+}
+
+#[test]
+fn test_alias_map() {
+    assert_eq!(alias_variants("Apache Kafka"), vec!["Apache Kafka", "apache kafka", "apache-kafka"]);
+    assert_eq!(alias_variants("rust"), vec!["rust"]);
+    assert_eq!(alias_variants("CI/CD"), vec!["ci-cd"]);
+
+    let mut resume = create_test_resume();
+    resume.skills[0].aliases = vec!["rust-lang".to_string(), "Rustlang".to_string()];
+    resume.skills[1].aliases = vec!["py".to_string()];
+    resume.projects[0].aliases = vec!["shop".to_string()];
+
+    let aliases = build_alias_map(&resume);
+    assert_eq!(aliases.skills["Rust"], "rust");
+    assert_eq!(aliases.skills["rust-lang"], "rust");
+    assert_eq!(aliases.skills["rustlang"], "rust");
+    assert_eq!(aliases.skills["apache-kafka"], "kafka");
+    assert_eq!(aliases.skills["PostgreSQL"], "postgresql");
+    assert!(!aliases.skills.contains_key("rust"));
+    assert_eq!(aliases.resolve_skill("py"), "python");
+    assert_eq!(aliases.resolve_skill("docker"), "docker");
+    assert_eq!(aliases.projects["e-commerce-platform"], "proj1");
+    assert_eq!(aliases.projects["shop"], "proj1");
+
+    // An auto-generated name that could mean two skills resolves to neither.
+    resume.skills[4].name = "Redis".to_string();
+    let aliases = build_alias_map(&resume);
+    assert!(!aliases.skills.contains_key("Redis"));
+    assert!(!aliases.skills.contains_key("redis"));
+}
+
+#[test]
+fn test_alias_tool_results() {
+    let mut resume = create_test_resume();
+    resume.skills[0].aliases = vec!["rust-lang".to_string()];
+
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let output_path = temp_dir.path().to_str().unwrap().to_string();
+    StaticGenerator::new(resume, output_path.clone())
+        .generate()
+        .expect("Failed to generate static site");
+
+    let canonical = fs::read_to_string(format!("{}/tools/get_projects_using_skill/rust.json", output_path)).unwrap();
+    for alias in ["Rust", "rust-lang"] {
+        let aliased = fs::read_to_string(format!(
+            "{}/tools/get_projects_using_skill/{}.json",
+            output_path, alias
+        ))
+        .unwrap();
+        assert_eq!(aliased, canonical);
+    }
+    assert!(fs::metadata(format!("{}/tools/get_skill_experience_summary/apache-kafka.json", output_path)).is_ok());
+    assert!(fs::metadata(format!("{}/tools/get_project_details/data-pipeline.json", output_path)).is_ok());
+    assert!(fs::metadata(format!("{}/tools/get_skills_for_project/Data Pipeline.json", output_path)).is_ok());
+
+    let resource: MCPResourceContent = serde_json::from_str(
+        &fs::read_to_string(format!("{}/resources/aliases.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(resource.uri, "resume://aliases");
+    let aliases: AliasMap = serde_json::from_str(&resource.text).unwrap();
+    assert_eq!(aliases.skills["rust-lang"], "rust");
+}
+
+#[test]
+fn test_alias_validation() {
+    let mut resume = create_test_resume();
+    resume.skills[0].aliases = vec!["py".to_string()];
+    resume.skills[1].aliases = vec!["PY".to_string(), "Docker".to_string()];
+
+    let report = validate(&resume);
+    let issues: Vec<(&str, IssueKind)> = report.errors().map(|i| (i.path.as_str(), i.kind)).collect();
+    assert_eq!(
+        issues,
+        vec![
+            ("resume.skills[1].aliases[0]", IssueKind::AmbiguousAlias),
+            ("resume.skills[1].aliases[1]", IssueKind::AmbiguousAlias),
+        ]
+    );
+}
+
+#[test]
+fn test_frequent_skill_itemsets() {
+    let project = |id: &str, skills: &[&str]| Project {
+        id: id.to_string(),
+        title: id.to_string(),
+        duration: None,
+        description: String::new(),
+        skills: skills.iter().map(|s| s.to_string()).collect(),
+        aliases: vec![],
+    };
+    let projects = vec![
+        project("p1", &["rust", "kafka", "postgresql", "docker"]),
+        project("p2", &["rust", "kafka", "postgresql", "redis"]),
+        project("p3", &["rust", "kafka", "go"]),
+        project("p4", &["python"]),
+    ];
+
+    let clusters = find_skill_clusters(&projects, 2, 5);
+    let found: Vec<(Vec<&str>, usize)> = clusters
+        .iter()
+        .map(|c| (c.skills.iter().map(String::as_str).collect(), c.support))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            (vec!["kafka", "rust"], 3),
+            (vec!["kafka", "postgresql", "rust"], 2),
+            (vec!["kafka", "postgresql"], 2),
+            (vec!["postgresql", "rust"], 2),
+        ]
+    );
+    // The triple is found even though neither project uses exactly those three skills.
+    assert_eq!(clusters[1].projects, vec!["p1", "p2"]);
+
+    let capped = find_skill_clusters(&projects, 2, 2);
+    assert!(capped.iter().all(|c| c.skills.len() == 2));
+    assert_eq!(capped.len(), 3);
+
+    let strict = find_skill_clusters(&projects, 3, 5);
+    assert_eq!(strict.len(), 1);
+    assert_eq!(strict[0].projects, vec!["p1", "p2", "p3"]);
+
+    assert_eq!(find_skill_clusters(&projects, 2, 5), clusters);
+}
+
+#[test]
+fn test_skill_cooccurrence_graph() {
+    let resume = create_test_resume();
+    let index = build_index(&resume);
+    let graph = build_skill_graph(&resume, &index);
+
+    assert_eq!(graph.nodes.len(), 6);
+    let edge = |a: &str, b: &str| {
+        graph
+            .edges
+            .iter()
+            .find(|e| e.source == a && e.target == b)
+            .unwrap_or_else(|| panic!("missing edge {} -- {}", a, b))
+    };
+    // proj1 and proj3 both use docker and postgresql, in exp1 and exp2 respectively.
+    let docker_postgresql = edge("docker", "postgresql");
+    assert_eq!(docker_postgresql.projects, 2);
+    assert_eq!(docker_postgresql.experiences, 2);
+    assert_eq!(docker_postgresql.weight, 4);
+    // kafka (proj2) and docker (proj1) only meet through exp1.
+    let docker_kafka = edge("docker", "kafka");
+    assert_eq!(docker_kafka.projects, 0);
+    assert_eq!(docker_kafka.experiences, 1);
+    assert!(graph.edges.iter().all(|e| e.source < e.target));
+
+    let related = graph.related("docker");
+    let related: Vec<(&str, usize)> = related.iter().map(|r| (r.id.as_str(), r.weight)).collect();
+    assert_eq!(
+        related,
+        vec![("postgresql", 4), ("python", 2), ("rust", 2), ("kafka", 1), ("redis", 1)]
+    );
+
+    let dot = graph.to_dot();
+    assert!(dot.starts_with("graph skills {"));
+    assert!(dot.contains("\"docker\" -- \"postgresql\" [weight=4, label=\"4\"];"));
+    let graphml = graph.to_graphml();
+    assert!(graphml.contains("<edge source=\"docker\" target=\"postgresql\">"));
+    assert!(graphml.contains("<data key=\"name\">Apache Kafka</data>"));
+
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let output_path = temp_dir.path().to_str().unwrap().to_string();
+    StaticGenerator::new(resume, output_path.clone())
+        .generate()
+        .expect("Failed to generate static site");
+
+    let resource: MCPResourceContent = serde_json::from_str(
+        &fs::read_to_string(format!("{}/resources/skill_graph.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    let written: SkillGraph = serde_json::from_str(&resource.text).unwrap();
+    assert_eq!(written, graph);
+    let dot_resource: MCPResourceContent = serde_json::from_str(
+        &fs::read_to_string(format!("{}/resources/skill_graph/dot.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(dot_resource.mime_type, "text/vnd.graphviz");
+    assert_eq!(fs::read_to_string(format!("{}/skill_graph.dot", output_path)).unwrap(), dot);
+    assert_eq!(fs::read_to_string(format!("{}/skill_graph.graphml", output_path)).unwrap(), graphml);
+
+    let result: MCPToolResult = serde_json::from_str(
+        &fs::read_to_string(format!("{}/tools/get_related_skills/kafka.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    let related: Vec<RelatedSkill> = serde_json::from_str(&result.content[0].text).unwrap();
+    assert_eq!(related[0].id, "redis");
+    assert_eq!(related[0].name, "Redis");
+}
+
+#[test]
+fn test_tfidf_and_jaccard() {
+    let tfidf = TfIdf::fit(["kafka streaming pipeline", "kafka consumer", "react dashboard"]);
+    assert_eq!(tfidf.documents, 3);
+    assert!(tfidf.idf["kafka"] < tfidf.idf["react"]);
+
+    let a = tfidf.vector("Kafka streaming pipeline");
+    let b = tfidf.vector("kafka streaming pipeline");
+    assert!((cosine(&a, &b) - 1.0).abs() < 1e-9);
+    assert_eq!(cosine(&a, &tfidf.vector("react dashboard")), 0.0);
+    assert!(tfidf.vector("unknown words").is_empty());
+
+    let x: std::collections::BTreeSet<&str> = ["rust", "kafka", "redis"].into_iter().collect();
+    let y: std::collections::BTreeSet<&str> = ["rust", "kafka", "docker"].into_iter().collect();
+    assert_eq!(jaccard(&x, &y), 0.5);
+    assert_eq!(jaccard(&std::collections::BTreeSet::<&str>::new(), &x), 0.0);
+}
+
+#[test]
+fn test_related_projects() {
+    let mut resume = create_test_resume();
+    resume.projects[1].description = "Real-time event processing pipeline for the platform".to_string();
+    let index = build_index(&resume);
+    let related = related_projects(&resume, &index);
+
+    let for_proj3 = &related["proj3"];
+    assert_eq!(for_proj3[0].id, "proj1");
+    assert_eq!(for_proj3[0].shared_skills, vec!["docker", "postgresql"]);
+    assert_eq!(for_proj3[0].skill_similarity, 0.5);
+    assert_eq!(for_proj3[0].employers, vec!["Tech Corp"]);
+
+    // proj2 shares no skill with proj3 and no description term, so it is not related.
+    assert!(for_proj3.iter().all(|r| r.id != "proj2"));
+
+    let for_proj1 = &related["proj1"];
+    let ids: Vec<&str> = for_proj1.iter().map(|r| r.id.as_str()).collect();
+    assert_eq!(ids, vec!["proj3", "proj2"]);
+    assert!(for_proj1[1].description_similarity > 0.0);
+    assert!(for_proj1.windows(2).all(|w| w[0].score >= w[1].score));
+
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let output_path = temp_dir.path().to_str().unwrap().to_string();
+    StaticGenerator::new(resume, output_path.clone())
+        .generate()
+        .expect("Failed to generate static site");
+
+    let result: MCPToolResult = serde_json::from_str(
+        &fs::read_to_string(format!("{}/tools/get_related_projects/proj3.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    let written: Vec<RelatedProject> = serde_json::from_str(&result.content[0].text).unwrap();
+    assert_eq!(&written, for_proj3);
+    assert!(fs::metadata(format!("{}/tools/get_related_projects/mobile-app-backend.json", output_path)).is_ok());
+}
+
+#[test]
+fn test_match_job_description() {
+    let mut resume = create_test_resume();
+    resume.skills[0].aliases = vec!["rust-lang".to_string()];
+    let index = build_index(&resume);
+    let artifacts = build_match_artifacts(&resume, &index);
+
+    assert_eq!(artifacts.vocabulary.documents, 5);
+    assert_eq!(artifacts.vocabulary.skills["apache kafka"], "kafka");
+    assert_eq!(artifacts.vocabulary.skills["rust-lang"], "rust");
+    assert_eq!(artifacts.vectors.experiences["exp2"].skills, vec!["docker", "postgresql", "python"]);
+    assert!(artifacts.vectors.projects["proj2"].terms.contains_key("pipeline"));
+
+    let job = "Backend engineer to build a real-time data pipeline in Rust-lang with Apache Kafka and Redis.";
+    let result = match_job_description(&artifacts, job);
+    assert_eq!(result.matched_skills, vec!["kafka", "redis", "rust"]);
+    assert_eq!(result.projects[0].id, "proj2");
+    assert_eq!(result.projects[0].matched_skills, vec!["rust", "kafka", "redis"]);
+    assert_eq!(result.experiences[0].id, "exp1");
+    assert!(result.score > 0.5 && result.score <= 1.0);
+
+    let unrelated = match_job_description(&artifacts, "Pastry chef for a French bakery");
+    assert!(unrelated.matched_skills.is_empty());
+    assert_eq!(unrelated.score, 0.0);
+
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let output_path = temp_dir.path().to_str().unwrap().to_string();
+    StaticGenerator::new(resume, output_path.clone())
+        .generate()
+        .expect("Failed to generate static site");
+
+    let read_resource = |name: &str| -> MCPResourceContent {
+        serde_json::from_str(
+            &fs::read_to_string(format!("{}/resources/match/{}.json", output_path, name)).unwrap(),
+        )
+        .unwrap()
+    };
+    let vocabulary = read_resource("vocabulary");
+    assert_eq!(vocabulary.uri, "resume://match/vocabulary");
+    let vectors = read_resource("vectors");
+    let published = MatchArtifacts {
+        vocabulary: serde_json::from_str(&vocabulary.text).unwrap(),
+        vectors: serde_json::from_str(&vectors.text).unwrap(),
+    };
+    assert_eq!(match_job_description(&published, job), result);
+}
+
+#[test]
+fn test_prompt_generation() {
+    let resume = create_test_resume();
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let output_path = temp_dir.path().to_str().unwrap().to_string();
+    StaticGenerator::new(resume.clone(), output_path.clone())
+        .generate()
+        .expect("Failed to generate static site");
+
+    let manifest: MCPManifest =
+        serde_json::from_str(&fs::read_to_string(format!("{}/mcp.json", output_path)).unwrap()).unwrap();
+    let names: Vec<&str> = manifest.capabilities.prompts.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec!["summarize_candidate_for_role", "write_cover_letter", "compare_with_job_description"]
+    );
+    assert_eq!(manifest.capabilities.prompts[0].arguments[0].name, "role");
+    assert!(manifest.capabilities.prompts[0].arguments[0].required);
+
+    let prompt: MCPPromptResult = serde_json::from_str(
+        &fs::read_to_string(format!("{}/prompts/summarize_candidate_for_role.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(prompt.messages.len(), 1);
+    assert_eq!(prompt.messages[0].role, "user");
+    let text = &prompt.messages[0].content.text;
+    assert!(text.contains(&format!("how well {} fits the role of {{{{role}}}}", resume.info.name)));
+    assert!(text.contains("- Senior Software Engineer at Tech Corp (2022-01 to present)"));
+    assert!(!text.contains("{{experiences}}"));
+
+    let variables = resume_variables(&resume);
+    assert_eq!(variables.len(), RESUME_VARIABLES.len());
+    assert_eq!(
+        render_prompt("Hi {{ name }}, about {{job}} {{", &variables),
+        format!("Hi {}, about {{{{job}}}} {{{{", resume.info.name)
+    );
+}
+
+#[test]
+fn test_configured_prompts() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let path = temp_dir.path().join("config.json");
+    let mut config = serde_json::to_value(create_test_config()).unwrap();
+    config["prompts"] = serde_json::json!([{
+        "name": "elevator_pitch",
+        "description": "Pitch the candidate to a company",
+        "arguments": [{"name": "company"}],
+        "template": "Pitch {{name}} to {{company}} using these skills: {{skills}}"
+    }]);
+    fs::write(&path, serde_json::to_string(&config).unwrap()).unwrap();
+
+    let config = load_config(&path).expect("Failed to load config");
+    assert_eq!(config.prompts.len(), 1);
+    assert!(!config.prompts[0].arguments[0].required);
+
+    let output_path = temp_dir.path().join("dist").to_str().unwrap().to_string();
+    let generator = StaticGenerator::new(config.resume, output_path.clone()).with_prompts(config.prompts);
+    generator.generate().expect("Failed to generate static site");
+    let prompt: MCPPromptResult = serde_json::from_str(
+        &fs::read_to_string(format!("{}/prompts/elevator_pitch.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    assert!(prompt.messages[0].content.text.starts_with("Pitch Test User to {{company}} using"));
+
+    let invalid = |template: &str, argument: &str| {
+        let prompt = PromptTemplate {
+            name: "broken".to_string(),
+            title: None,
+            description: String::new(),
+            arguments: vec![PromptArgument {
+                name: argument.to_string(),
+                description: None,
+                required: true,
+            }],
+            template: template.to_string(),
+        };
+        check_prompts(&[prompt]).unwrap_err().to_string()
+    };
+    assert_eq!(invalid("{{company}} {{salary}}", "company"), "invalid prompt 'broken': unknown placeholder '{{salary}}'");
+    assert_eq!(invalid("{{name}}", "name"), "invalid prompt 'broken': argument 'name' shadows a resume variable");
+    assert!(check_prompts(&[default_prompts()[0].clone(), default_prompts()[0].clone()]).is_err());
+}
+
+fn conforms(value: &serde_json::Value, schema: &serde_json::Value) -> bool {
+    use serde_json::Value;
+    if let Some(options) = schema.get("oneOf").and_then(Value::as_array) {
+        return options.iter().filter(|option| conforms(value, option)).count() == 1;
+    }
+    if let Some(expected) = schema.get("const") && value != expected {
+        return false;
+    }
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array) && !allowed.contains(value) {
+        return false;
+    }
+    let type_matches = |name: &str| match name {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "number" => value.is_number(),
+        "null" => value.is_null(),
+        _ => false,
+    };
+    let types_match = match schema.get("type") {
+        Some(Value::String(name)) => type_matches(name),
+        Some(Value::Array(names)) => names.iter().filter_map(Value::as_str).any(type_matches),
+        _ => true,
+    };
+    if !types_match {
+        return false;
+    }
+    match value {
+        Value::Object(map) => {
+            let required = schema.get("required").and_then(Value::as_array).cloned().unwrap_or_default();
+            let properties = schema.get("properties").and_then(Value::as_object);
+            required.iter().filter_map(Value::as_str).all(|key| map.contains_key(key))
+                && map.iter().all(|(key, field)| {
+                    match properties.and_then(|p| p.get(key)).or(schema.get("additionalProperties")) {
+                        Some(field_schema) => conforms(field, field_schema),
+                        None => properties.is_none(),
+                    }
+                })
+        }
+        Value::Array(items) => match schema.get("items") {
+            Some(item_schema) => items.iter().all(|item| conforms(item, item_schema)),
+            None => true,
+        },
+        _ => true,
+    }
+}
+
+#[test]
+fn test_structured_tool_output() {
+    let mut resume = create_test_resume();
+    resume.certifications = create_test_certifications();
+    resume.publications = create_test_publications();
+    resume.skills[0].proficiency = Some(Proficiency::Expert);
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let output_path = temp_dir.path().to_str().unwrap().to_string();
+    StaticGenerator::new(resume, output_path.clone())
+        .generate()
+        .expect("Failed to generate static site");
+
+    let manifest: MCPManifest =
+        serde_json::from_str(&fs::read_to_string(format!("{}/mcp.json", output_path)).unwrap()).unwrap();
+    let schemas: HashMap<String, serde_json::Value> = manifest
+        .capabilities
+        .tools
+        .into_iter()
+        .map(|tool| (tool.name, tool.output_schema.expect("Tool without output schema")))
+        .collect();
+    for schema in schemas.values() {
+        assert_eq!(schema["type"], "object");
+    }
+
+    let mut checked = 0;
+    let mut pending = vec![std::path::PathBuf::from(format!("{}/tools", output_path))];
+    while let Some(dir) = pending.pop() {
+        for entry in fs::read_dir(&dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                pending.push(path);
+                continue;
+            }
+            let relative = path.strip_prefix(format!("{}/tools", output_path)).unwrap();
+            let tool = relative.components().next().unwrap().as_os_str().to_str().unwrap();
+            let tool = tool.strip_suffix(".json").unwrap_or(tool);
+            let result: MCPToolResult = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+            let structured = result.structured_content.expect("Tool result without structured content");
+            let text: serde_json::Value = serde_json::from_str(&result.content[0].text).unwrap();
+            if text.is_object() {
+                assert_eq!(structured, text);
+            } else {
+                assert_eq!(structured, serde_json::json!({ "result": text }));
+            }
+            assert!(conforms(&structured, &schemas[tool]), "{} does not match the schema of {}", path.display(), tool);
+            checked += 1;
+        }
+    }
+    assert!(checked > 50);
+
+    let skills_for_project: MCPToolResult = serde_json::from_str(
+        &fs::read_to_string(format!("{}/tools/get_skills_for_project/proj1.json", output_path)).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(skills_for_project.structured_content.unwrap()["result"][0]["id"], "rust");
+
+    let artifacts = build_match_artifacts(&create_test_resume(), &build_index(&create_test_resume()));
+    let job_match = serde_json::to_value(match_job_description(&artifacts, "Rust and Kafka")).unwrap();
+    assert!(conforms(&job_match, &schemas["match_job_description"]));
+    assert!(!conforms(&serde_json::json!({ "score": "high" }), &schemas["match_job_description"]));
+}
+
+struct ProjectsByCategory;
+
+impl StaticTool for ProjectsByCategory {
+    fn name(&self) -> &str {
+        "get_projects_by_category"
+    }
+
+    fn description(&self) -> &str {
+        "Get all projects using a skill of a given category"
+    }
+
+    fn input_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {"category": {"type": "string"}},
+            "required": ["category"]
+        })
+    }
+
+    fn arguments(&self, context: &ToolContext) -> Vec<Vec<String>> {
+        let mut categories: Vec<String> = context.resume.skills.iter().map(|s| s.category.clone()).collect();
+        categories.sort();
+        categories.dedup();
+        categories.into_iter().map(|category| vec![category]).collect()
+    }
+
+    fn call(&self, context: &ToolContext, arguments: &[String]) -> Result<MCPToolResult> {
+        let projects: Vec<&str> = context
+            .resume
+            .projects
+            .iter()
+            .filter(|p| p.skills.iter().any(|id| context.index.skill_lookup[id].category == arguments[0]))
+            .map(|p| p.id.as_str())
+            .collect();
+        MCPToolResult::new(&projects)
+    }
+}
+
+struct FixedBasicInfo;
+
+impl StaticTool for FixedBasicInfo {
+    fn name(&self) -> &str {
+        "get_basic_info"
+    }
+
+    fn description(&self) -> &str {
+        "Get a redacted greeting"
+    }
+
+    fn input_schema(&self) -> serde_json::Value {
+        serde_json::json!({"type": "object", "properties": {}, "required": []})
+    }
+
+    fn arguments(&self, _: &ToolContext) -> Vec<Vec<String>> {
+        vec![vec![]]
+    }
+
+    fn call(&self, _: &ToolContext, _: &[String]) -> Result<MCPToolResult> {
+        MCPToolResult::new(&serde_json::json!({"greeting": "hello"}))
+    }
+}
+
+#[test]
+fn test_custom_static_tools() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let output_path = temp_dir.path().to_str().unwrap().to_string();
+    let builtin_count = builtin_tools().len();
+    let generator = StaticGenerator::new(create_test_resume(), output_path.clone())
+        .with_tool(ProjectsByCategory)
+        .with_tool(FixedBasicInfo);
+    assert_eq!(generator.tools.len(), builtin_count + 1);
+    generator.generate().expect("Failed to generate static site");
+
+    let manifest: MCPManifest =
+        serde_json::from_str(&fs::read_to_string(format!("{}/mcp.json", output_path)).unwrap()).unwrap();
+    assert_eq!(manifest.capabilities.tools.len(), builtin_count + 1);
+    let custom = manifest.capabilities.tools.last().unwrap();
+    assert_eq!(custom.name, "get_projects_by_category");
+    assert!(custom.output_schema.is_none());
+    let basic_info = manifest.capabilities.tools.iter().find(|t| t.name == "get_basic_info").unwrap();
+    assert_eq!(basic_info.description, "Get a redacted greeting");
+
+    let read_result = |path: &str| -> serde_json::Value {
+        let result: MCPToolResult =
+            serde_json::from_str(&fs::read_to_string(format!("{}/tools/{}", output_path, path)).unwrap()).unwrap();
+        result.structured_content.unwrap()
+    };
+    assert_eq!(
+        read_result("get_projects_by_category/backend.json"),
+        serde_json::json!({"result": ["proj1", "proj2", "proj3"]})
+    );
+    assert_eq!(read_result("get_basic_info.json"), serde_json::json!({"greeting": "hello"}));
+
+    let context = generator.tool_context();
+    let shared = generator.tools.iter().find(|t| t.name() == "get_shared_skills").unwrap();
+    assert!(shared.arguments(&context).contains(&vec!["proj2".to_string(), "proj1".to_string()]));
+    assert!(fs::metadata(format!("{}/tools/get_shared_skills/proj2/proj1.json", output_path)).is_ok());
+    let matcher = generator.tools.iter().find(|t| t.name() == "match_job_description").unwrap();
+    assert!(!matcher.precomputed());
+    assert!(fs::metadata(format!("{}/tools/match_job_description", output_path)).is_err());
+    let err = matcher
+        .call(&context, &["Rust engineer".to_string()])
+        .expect_err("A tool that is not pre-computed cannot be called");
+    assert!(matches!(&err, Error::NotPrecomputed { tool } if tool == "match_job_description"));
+    assert_eq!(err.exit_code(), 7);
+}
+
+#[test]
+fn test_builtin_tools_reject_unknown_arguments() {
+    let generator = StaticGenerator::new(create_test_resume(), String::new());
+    let context = generator.tool_context();
+    let mut checked = 0;
+    for tool in generator.tools.iter().filter(|tool| tool.precomputed()) {
+        let schema = tool.input_schema();
+        let required = schema["required"].as_array().unwrap();
+        if required.is_empty() {
+            continue;
+        }
+        let arguments = vec!["nope".to_string(); required.len()];
+        let err = tool
+            .call(&context, &arguments)
+            .expect_err(&format!("{} should reject an unknown argument", tool.name()));
+        match &err {
+            Error::UnknownArgument { argument, value } => {
+                assert_eq!(argument, required[0].as_str().unwrap(), "{}", tool.name());
+                assert_eq!(value, "nope");
+            }
+            other => panic!("Expected unknown argument error from {}, got {:?}", tool.name(), other),
+        }
+        assert_eq!(err.exit_code(), 7);
+        checked += 1;
+    }
+    assert_eq!(checked, 15);
+
+    let shared = generator.tools.iter().find(|t| t.name() == "get_shared_skills").unwrap();
+    let err = shared.call(&context, &["proj1".to_string()]).unwrap_err();
+    assert_eq!(err.to_string(), "unknown project_b ''");
+}
+
+#[test]
+fn test_custom_tools_from_config() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let path = temp_dir.path().join("config.json");
+    let mut config = serde_json::to_value(create_test_config()).unwrap();
+    config["custom_tools"] = serde_json::json!([
+        {
+            "name": "get_projects_by_skill_category",
+            "description": "Get all projects using a skill of a given category",
+            "arguments": [{"name": "category", "domain": "skill_category", "description": "Skill category"}],
+            "select": "projects",
+            "where": [{"field": "skill.category", "equals": "{{category}}"}],
+            "fields": ["id", "title"]
+        },
+        {
+            "name": "get_skills_in_category_used_at",
+            "description": "Get the skills of a category used in an experience",
+            "arguments": [
+                {"name": "category", "domain": "skill_category"},
+                {"name": "experience", "domain": "experience"}
+            ],
+            "select": "skills",
+            "where": [
+                {"field": "category", "equals": "{{category}}"},
+                {"field": "experience", "equals": "{{experience}}"}
+            ]
+        }
+    ]);
+    fs::write(&path, serde_json::to_string(&config).unwrap()).unwrap();
+
+    let config = load_config(&path).expect("Failed to load config");
+    assert_eq!(config.custom_tools.len(), 2);
+    assert_eq!(config.custom_tools[0].select, CustomToolTarget::Projects);
+    assert_eq!(config.custom_tools[1].arguments[1].domain, ArgumentDomain::Experience);
+
+    let output_path = temp_dir.path().join("dist").to_str().unwrap().to_string();
+    StaticGenerator::new(config.resume, output_path.clone())
+        .with_custom_tools(config.custom_tools.clone())
+        .generate()
+        .expect("Failed to generate static site");
+
+    let manifest: MCPManifest =
+        serde_json::from_str(&fs::read_to_string(format!("{}/mcp.json", output_path)).unwrap()).unwrap();
+    let tool = manifest
+        .capabilities
+        .tools
+        .iter()
+        .find(|t| t.name == "get_projects_by_skill_category")
+        .unwrap();
+    assert_eq!(tool.input_schema["required"], serde_json::json!(["category"]));
+    assert_eq!(tool.input_schema["properties"]["category"]["description"], "Skill category");
+    let item_schema = &tool.output_schema.as_ref().unwrap()["properties"]["result"]["items"];
+    assert_eq!(item_schema["required"], serde_json::json!(["id", "title"]));
+
+    let read_result = |path: &str| -> serde_json::Value {
+        let result: MCPToolResult =
+            serde_json::from_str(&fs::read_to_string(format!("{}/tools/{}", output_path, path)).unwrap()).unwrap();
+        result.structured_content.unwrap()["result"].clone()
+    };
+    assert_eq!(
+        read_result("get_projects_by_skill_category/devops.json"),
+        serde_json::json!([
+            {"id": "proj1", "title": "E-commerce Platform"},
+            {"id": "proj3", "title": "Mobile App Backend"}
+        ])
+    );
+    let ids = |value: serde_json::Value| -> Vec<String> {
+        value.as_array().unwrap().iter().map(|s| s["id"].as_str().unwrap().to_string()).collect()
+    };
+    assert_eq!(ids(read_result("get_skills_in_category_used_at/backend/exp2.json")), vec!["python", "postgresql"]);
+    assert_eq!(ids(read_result("get_skills_in_category_used_at/devops/exp1.json")), vec!["docker"]);
+
+    let mut resume = create_test_resume();
+    resume.skills[1].category = "ci/cd".to_string();
+    let generator = StaticGenerator::new(resume, output_path).with_custom_tools(config.custom_tools);
+    let context = generator.tool_context();
+    assert_eq!(ArgumentDomain::SkillCategory.values(&context), vec!["backend", "ci/cd", "devops"]);
+    let err = generator.generate().expect_err("A category that is not a file name should fail");
+    match &err {
+        Error::CustomTool { name, message } => {
+            assert_eq!(name, "get_projects_by_skill_category");
+            assert!(message.contains("'ci/cd'"), "Unexpected message: {}", message);
+        }
+        other => panic!("Expected custom tool error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_custom_tool_errors() {
+    let tool = |value: serde_json::Value| -> CustomTool { serde_json::from_value(value).unwrap() };
+    let base = serde_json::json!({
+        "name": "by_category",
+        "description": "",
+        "arguments": [{"name": "category", "domain": "skill_category"}],
+        "select": "projects",
+        "where": [{"field": "skill.category", "equals": "{{category}}"}]
+    });
+
+    let mut bad_field = base.clone();
+    bad_field["where"][0]["field"] = serde_json::json!("category");
+    let err = check_custom_tools(&[tool(bad_field)]).unwrap_err();
+    assert_eq!(err.exit_code(), 4);
+    assert!(err.to_string().starts_with("invalid custom tool 'by_category': cannot filter projects on 'category'"));
+
+    let mut bad_argument = base.clone();
+    bad_argument["where"][0]["equals"] = serde_json::json!("{{kind}}");
+    assert_eq!(
+        check_custom_tools(&[tool(bad_argument)]).unwrap_err().to_string(),
+        "invalid custom tool 'by_category': unknown argument '{{kind}}'"
+    );
+
+    let mut bad_projection = base.clone();
+    bad_projection["fields"] = serde_json::json!(["id", "budget"]);
+    assert_eq!(
+        check_custom_tools(&[tool(bad_projection)]).unwrap_err().to_string(),
+        "invalid custom tool 'by_category': unknown field 'budget'"
+    );
+
+    let mut bad_name = base.clone();
+    bad_name["name"] = serde_json::json!("../escape");
+    assert!(check_custom_tools(&[tool(bad_name.clone())]).is_err());
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let generator = StaticGenerator::new(create_test_resume(), temp_dir.path().to_str().unwrap().to_string())
+        .with_custom_tools(vec![tool(bad_name)]);
+    assert!(matches!(generator.generate(), Err(Error::CustomTool { .. })));
+    assert!(fs::metadata(temp_dir.path().join("mcp.json")).is_err());
+
+    let duplicated = check_custom_tools(&[tool(base.clone()), tool(base.clone())]).unwrap_err();
+    assert_eq!(duplicated.to_string(), "invalid custom tool 'by_category': defined more than once");
+
+    let mut shadowing = base;
+    shadowing["name"] = serde_json::json!("get_basic_info");
+    let err = check_custom_tools(&[tool(shadowing)]).unwrap_err();
+    assert_eq!(err.to_string(), "invalid custom tool 'get_basic_info': has the name of a built-in tool");
+    assert_eq!(err.exit_code(), 4);
+}